@@ -1,6 +1,8 @@
+use crate::types::{now_timestamp, BboMsg, OrderBookMsg, Side, TradeMsg};
+use binance::account::Account;
 use binance::api::*;
-use binance::market::*;
-use serde::{Deserialize};
+use binance::market::Market;
+use serde::Deserialize;
 use std::fs;
 
 #[derive(Debug, Deserialize)]
@@ -17,6 +19,7 @@ struct Config {
 
 pub struct BinanceClient {
     market: Market,
+    account: Account,
     default_pair: String,
 }
 
@@ -39,18 +42,125 @@ impl BinanceClient {
         let config: Config = serde_yaml::from_str(&config_str)
             .map_err(|e| BinanceError::ConfigError(format!("Failed to parse YAML: {}", e)))?;
 
-        let market = Binance::new(
+        let market: Market = Binance::new(
+            Some(config.binance.api_key.clone()),
+            Some(config.binance.api_secret.clone()),
+        );
+        let account: Account = Binance::new(
             Some(config.binance.api_key),
             Some(config.binance.api_secret),
         );
 
         Ok(BinanceClient {
             market,
+            account,
             default_pair: config.binance.default_pair,
         })
     }
 
-    pub fn get_depth(&self) -> Result<String, BinanceError> {
-        Ok(r#"{"bids": [], "asks": []}"#.to_string())
-    }       
+    fn pair_or_default<'a>(&'a self, symbol: Option<&'a str>) -> &'a str {
+        symbol.unwrap_or(&self.default_pair)
+    }
+
+    pub fn get_depth(&self, symbol: Option<&str>) -> Result<(Vec<(f64, f64)>, Vec<(f64, f64)>), BinanceError> {
+        let depth = self.market.get_depth(self.pair_or_default(symbol))?;
+        let bids = depth.bids.into_iter().map(|b| (b.price, b.qty)).collect();
+        let asks = depth.asks.into_iter().map(|a| (a.price, a.qty)).collect();
+        Ok((bids, asks))
+    }
+
+    pub fn get_bid(&self, symbol: Option<&str>) -> Result<f64, BinanceError> {
+        let ticker = self.market.get_book_ticker(self.pair_or_default(symbol))?;
+        Ok(ticker.bid_price)
+    }
+
+    pub fn get_ask(&self, symbol: Option<&str>) -> Result<f64, BinanceError> {
+        let ticker = self.market.get_book_ticker(self.pair_or_default(symbol))?;
+        Ok(ticker.ask_price)
+    }
+
+    pub fn get_spread(&self, symbol: Option<&str>) -> Result<f64, BinanceError> {
+        let ticker = self.market.get_book_ticker(self.pair_or_default(symbol))?;
+        Ok(ticker.ask_price - ticker.bid_price)
+    }
+
+    pub fn get_balance(&self, asset: &str) -> Result<f64, BinanceError> {
+        let balance = self.account.get_balance(asset)?;
+        Ok(balance.free)
+    }
+
+    pub fn add_order(
+        &self,
+        symbol: &str,
+        side: &str,
+        price: f64,
+        quantity: f64,
+    ) -> Result<u64, BinanceError> {
+        let side_lower = side.to_lowercase();
+        let transaction = match side_lower.as_str() {
+            "buy" => self.account.limit_buy(symbol, quantity, price)?,
+            "sell" => self.account.limit_sell(symbol, quantity, price)?,
+            _ => {
+                return Err(BinanceError::ConfigError(
+                    "Side must be 'buy' or 'sell'".to_string(),
+                ))
+            }
+        };
+        Ok(transaction.order_id)
+    }
+
+    pub fn cancel_order(&self, symbol: &str, order_id: u64) -> Result<bool, BinanceError> {
+        self.account.cancel_order(symbol, order_id)?;
+        Ok(true)
+    }
+
+    /// Normalized top-of-book snapshot, exchange-tagged so callers can
+    /// compare Binance and Kraken directly.
+    pub fn get_bbo_msg(&self, symbol: Option<&str>) -> Result<BboMsg, BinanceError> {
+        let pair = self.pair_or_default(symbol).to_string();
+        let ticker = self.market.get_book_ticker(&pair)?;
+        Ok(BboMsg {
+            exchange: "binance".to_string(),
+            symbol: pair,
+            timestamp: now_timestamp(),
+            bid_price: ticker.bid_price,
+            bid_quantity: ticker.bid_qty,
+            ask_price: ticker.ask_price,
+            ask_quantity: ticker.ask_qty,
+        })
+    }
+
+    /// Normalized order book snapshot with full (price, quantity) levels.
+    pub fn get_orderbook_msg(&self, symbol: Option<&str>) -> Result<OrderBookMsg, BinanceError> {
+        let pair = self.pair_or_default(symbol).to_string();
+        let depth = self.market.get_depth(&pair)?;
+        Ok(OrderBookMsg {
+            exchange: "binance".to_string(),
+            symbol: pair,
+            timestamp: now_timestamp(),
+            bids: depth.bids.into_iter().map(|b| (b.price, b.qty)).collect(),
+            asks: depth.asks.into_iter().map(|a| (a.price, a.qty)).collect(),
+        })
+    }
+
+    /// Normalized recent-trades view, matching `KrakenClient::get_trade_msgs`.
+    pub fn get_trade_msgs(&self, symbol: Option<&str>) -> Result<Vec<TradeMsg>, BinanceError> {
+        let pair = self.pair_or_default(symbol).to_string();
+        let trades = self.market.get_trades(&pair)?;
+        Ok(trades
+            .into_iter()
+            .map(|t| TradeMsg {
+                exchange: "binance".to_string(),
+                symbol: pair.clone(),
+                // Binance reports `time` in epoch milliseconds; every other
+                // timestamp in this module (and `now_timestamp`) is epoch
+                // seconds, so convert to keep `TradeMsg.timestamp` comparable
+                // across venues.
+                timestamp: t.time as f64 / 1000.0,
+                side: if t.is_buyer_maker { Side::Sell } else { Side::Buy },
+                price: t.price,
+                quantity: t.qty,
+            })
+            .collect())
+    }
 }
@@ -1,6 +1,10 @@
 use crate::kraken::{KrakenClient, KrakenError};
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct OrderResponse {
@@ -70,31 +74,82 @@ where
     }
 }
 
-impl KrakenClient {
-    pub fn get_open_orders_raw(&self) -> Result<String, KrakenError> {
-        let nonce = self.generate_nonce();
-        let body = format!("nonce={}", nonce);
+/// The exchange's confirmation that the dead man's switch timer is armed.
+#[derive(Debug)]
+pub struct DeadMansSwitch {
+    pub current_time: String,
+    pub trigger_time: String,
+}
+
+/// A shutdown flag the keep-alive worker can be woken from mid-sleep, so
+/// disarming doesn't have to wait out the rest of a `rearm_interval`.
+struct ShutdownSignal {
+    shutdown: Mutex<bool>,
+    condvar: Condvar,
+}
 
-        let path = "/0/private/OpenOrders";
-        let url = format!("https://api.kraken.com{}", path);
+impl ShutdownSignal {
+    fn trigger(&self) {
+        *self.shutdown.lock().expect("dead man's switch mutex poisoned") = true;
+        self.condvar.notify_all();
+    }
+
+    fn is_set(&self) -> bool {
+        *self.shutdown.lock().expect("dead man's switch mutex poisoned")
+    }
+
+    /// Sleeps for `timeout`, waking immediately if `trigger` is called first.
+    fn wait(&self, timeout: Duration) {
+        let guard = self.shutdown.lock().expect("dead man's switch mutex poisoned");
+        if *guard {
+            return;
+        }
+        let _ = self.condvar.wait_timeout(guard, timeout);
+    }
+}
 
-        let message = self.create_signature_message(path, &nonce, &body);
-        let signature = self.sign_message(&message)?;
+/// Owns the background thread that keeps a dead man's switch re-armed.
+/// Dropping it stops the keep-alive and disarms the timer (`timeout: 0`) so
+/// a clean shutdown doesn't leave orders to be force-cancelled later.
+pub struct DeadMansSwitchGuard {
+    signal: Arc<ShutdownSignal>,
+    worker: Option<JoinHandle<()>>,
+}
 
-        let response = self.client
-            .post(&url)
-            .header("API-Key", &self.api_key)
-            .header("API-Sign", signature)
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(body)
-            .send()
-            .map_err(KrakenError::HttpError)?;
+impl Drop for DeadMansSwitchGuard {
+    fn drop(&mut self) {
+        self.signal.trigger();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
 
-        let json_text = response.text()
-            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
+fn extract_order_response(result: &serde_json::Value) -> Result<OrderResponse, KrakenError> {
+    let txid = result
+        .get("txid")
+        .and_then(|t| t.as_array())
+        .ok_or_else(|| KrakenError::ParseError("Missing txid array".to_string()))?
+        .iter()
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect::<Vec<String>>();
+
+    let description = result
+        .get("descr")
+        .and_then(|d| d.get("order"))
+        .and_then(|o| o.as_str())
+        .unwrap_or("No description available")
+        .to_string();
+
+    Ok(OrderResponse { txid, description })
+}
 
-        Ok(json_text)
+impl KrakenClient {
+    pub fn get_open_orders_raw(&self) -> Result<String, KrakenError> {
+        let result = self.query("OpenOrders", serde_json::json!({}))?;
+        serde_json::to_string(&result).map_err(|e| KrakenError::ParseError(e.to_string()))
     }
+
     pub fn edit_order(
         &self,
         txid: &str,
@@ -111,110 +166,27 @@ impl KrakenClient {
             ));
         }
 
-        let nonce = self.generate_nonce();
-        
-        let mut params = vec![
-            ("nonce".to_string(), nonce.clone()),
-            ("ordertype".to_string(), "limit".to_string()),
-            ("type".to_string(), side_lower),
-            ("volume".to_string(), volume.to_string()),
-            ("price".to_string(), price.to_string()),
-            ("pair".to_string(), pair.to_string()),
-            ("txid".to_string(), txid.to_string()),
-        ];
-
+        let mut params = serde_json::json!({
+            "ordertype": "limit",
+            "type": side_lower,
+            "volume": volume.to_string(),
+            "price": price.to_string(),
+            "pair": pair,
+            "txid": txid,
+        });
         if let Some(userref) = new_userref {
-            params.push(("userref".to_string(), userref.to_string()));
-        }
-
-        let body = params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<String>>()
-            .join("&");
-
-        let path = "/0/private/EditOrder";
-        let url = format!("https://api.kraken.com{}", path);
-
-        let message = self.create_signature_message(path, &nonce, &body);
-        let signature = self.sign_message(&message)?;
-
-        let response = self.client
-            .post(&url)
-            .header("API-Key", &self.api_key)
-            .header("API-Sign", signature)
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(body)
-            .send()
-            .map_err(KrakenError::HttpError)?;
-
-        let json: serde_json::Value = response.json()
-            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
-
-        if let Some(errors) = json.get("error").and_then(serde_json::Value::as_array) {
-            if !errors.is_empty() {
-                return Err(KrakenError::ParseError(
-                    format!("Kraken API error: {:?}", errors)
-                ));
-            }
+            params["userref"] = serde_json::Value::String(userref.to_string());
         }
 
-        let result = json.get("result")
-            .ok_or_else(|| KrakenError::MissingField("result".to_string()))?;
-
-        let txid = result.get("txid")
-            .and_then(|t| t.as_array())
-            .ok_or_else(|| KrakenError::ParseError("Missing txid array".to_string()))?
-            .iter()
-            .filter_map(|v| v.as_str().map(|s| s.to_string()))
-            .collect::<Vec<String>>();
-
-        let description = result.get("descr")
-            .and_then(|d| d.get("order"))
-            .and_then(|o| o.as_str())
-            .unwrap_or("No description available")
-            .to_string();
-
-        Ok(OrderResponse {
-            txid,
-            description,
-        })
+        let result = self.query("EditOrder", params)?;
+        extract_order_response(&result)
     }
 
     pub fn cancel_order(&self, txid: &str) -> Result<bool, KrakenError> {
-        let nonce = self.generate_nonce();
-        let body = format!("nonce={}&txid={}", nonce, txid);
-
-        let path = "/0/private/CancelOrder";
-        let url = format!("https://api.kraken.com{}", path);
-
-        let message = self.create_signature_message(path, &nonce, &body);
-        let signature = self.sign_message(&message)?;
-
-        let response = self.client
-            .post(&url)
-            .header("API-Key", &self.api_key)
-            .header("API-Sign", signature)
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(body)
-            .send()
-            .map_err(KrakenError::HttpError)?;
-
-        let json: serde_json::Value = response.json()
-            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
-
-        if let Some(errors) = json.get("error").and_then(serde_json::Value::as_array) {
-            if !errors.is_empty() {
-                return Err(KrakenError::ParseError(
-                    format!("Kraken API error: {:?}", errors)
-                ));
-            }
-        }
-
-        let result = json.get("result")
-            .ok_or_else(|| KrakenError::MissingField("result".to_string()))?;
+        let result = self.query("CancelOrder", serde_json::json!({ "txid": txid }))?;
 
-        let count = result.get("count")
+        let count = result
+            .get("count")
             .and_then(|c| c.as_u64())
             .ok_or_else(|| KrakenError::ParseError("Missing count field".to_string()))?;
 
@@ -222,39 +194,10 @@ impl KrakenClient {
     }
 
     pub fn get_balance(&self) -> Result<HashMap<String, f64>, KrakenError> {
-        let nonce = self.generate_nonce();
-        let body = format!("nonce={}", nonce);
-
-        let path = "/0/private/Balance";
-        let url = format!("https://api.kraken.com{}", path);
-
-        let message = self.create_signature_message(path, &nonce, &body);
-        let signature = self.sign_message(&message)?;
-
-        let response = self.client
-            .post(&url)
-            .header("API-Key", &self.api_key)
-            .header("API-Sign", signature)
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(body)
-            .send()
-            .map_err(KrakenError::HttpError)?;
-
-        let json: serde_json::Value = response.json()
-            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
-
-        if let Some(errors) = json.get("error").and_then(serde_json::Value::as_array) {
-            if !errors.is_empty() {
-                return Err(KrakenError::ParseError(
-                    format!("Kraken API error: {:?}", errors)
-                ));
-            }
-        }
-
-        let result = json.get("result")
-            .ok_or_else(|| KrakenError::MissingField("result".to_string()))?;
+        let result = self.query("Balance", serde_json::json!({}))?;
 
-        let balances = result.as_object()
+        let balances = result
+            .as_object()
             .ok_or_else(|| KrakenError::ParseError("Expected result to be an object".to_string()))?
             .iter()
             .filter_map(|(k, v)| {
@@ -275,68 +218,72 @@ impl KrakenClient {
             ));
         }
 
-        let nonce = self.generate_nonce();
-        
-        let params = vec![
-            ("nonce".to_string(), nonce.clone()),
-            ("ordertype".to_string(), "limit".to_string()),
-            ("type".to_string(), side_lower),
-            ("volume".to_string(), volume.to_string()),
-            ("price".to_string(), price.to_string()),
-            ("pair".to_string(), pair.to_string()),
-        ];
-
-        let body = params
-            .iter()
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<String>>()
-            .join("&");
-
-        let path = "/0/private/AddOrder";
-        let url = format!("https://api.kraken.com{}", path);
-
-        let message = self.create_signature_message(path, &nonce, &body);
-        let signature = self.sign_message(&message)?;
-
-        let response = self.client
-            .post(&url)
-            .header("API-Key", &self.api_key)
-            .header("API-Sign", signature)
-            .header("Content-Type", "application/x-www-form-urlencoded")
-            .body(body)
-            .send()
-            .map_err(KrakenError::HttpError)?;
-
-        let json: serde_json::Value = response.json()
-            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
-
-        if let Some(errors) = json.get("error").and_then(serde_json::Value::as_array) {
-            if !errors.is_empty() {
-                return Err(KrakenError::ParseError(
-                    format!("Kraken API error: {:?}", errors)
-                ));
-            }
-        }
+        let params = serde_json::json!({
+            "ordertype": "limit",
+            "type": side_lower,
+            "volume": volume.to_string(),
+            "price": price.to_string(),
+            "pair": pair,
+        });
+
+        let result = self.query("AddOrder", params)?;
+        extract_order_response(&result)
+    }
 
-        let result = json.get("result")
-            .ok_or_else(|| KrakenError::MissingField("result".to_string()))?;
+    /// Fetches a 15-minute token for authenticated WebSocket subscriptions
+    /// (`openOrders`, `ownTrades`) against `wss://ws-auth.kraken.com`, reusing
+    /// the same signing path as every other private REST call.
+    pub fn get_websockets_token(&self) -> Result<String, KrakenError> {
+        let result = self.query("GetWebSocketsToken", serde_json::json!({}))?;
+        result
+            .get("token")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| KrakenError::MissingField("token".to_string()))
+    }
 
-        let txid = result.get("txid")
-            .and_then(|t| t.as_array())
-            .ok_or_else(|| KrakenError::ParseError("Missing txid array".to_string()))?
-            .iter()
-            .filter_map(|v| v.as_str().map(|s| s.to_string()))
-            .collect::<Vec<String>>();
-
-        let description = result.get("descr")
-            .and_then(|d| d.get("order"))
-            .and_then(|o| o.as_str())
-            .unwrap_or("No description available")
-            .to_string();
-
-        Ok(OrderResponse {
-            txid,
-            description,
+    /// Arms (or refreshes) the exchange-side dead man's switch: if this
+    /// client doesn't call it again within `timeout_secs`, Kraken cancels
+    /// every open order for the account. Pass `0` to disarm immediately.
+    pub fn cancel_all_orders_after(&self, timeout_secs: u64) -> Result<DeadMansSwitch, KrakenError> {
+        let result = self.query(
+            "CancelAllOrdersAfter",
+            serde_json::json!({ "timeout": timeout_secs }),
+        )?;
+
+        Ok(DeadMansSwitch {
+            current_time: result.get("currentTime").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            trigger_time: result.get("triggerTime").and_then(|v| v.as_str()).unwrap_or("").to_string(),
         })
     }
-}
\ No newline at end of file
+
+    /// Spawns a background thread that re-arms the dead man's switch every
+    /// `rearm_interval`, so a crashed process still gets flattened after
+    /// `timeout_secs` with no further action from it. Disarms on drop.
+    pub fn arm_dead_mans_switch_keepalive(
+        self: Arc<Self>,
+        timeout_secs: u64,
+        rearm_interval: Duration,
+    ) -> DeadMansSwitchGuard {
+        let signal = Arc::new(ShutdownSignal {
+            shutdown: Mutex::new(false),
+            condvar: Condvar::new(),
+        });
+        let worker_signal = Arc::clone(&signal);
+
+        let worker = thread::spawn(move || {
+            while !worker_signal.is_set() {
+                if let Err(e) = self.cancel_all_orders_after(timeout_secs) {
+                    eprintln!("failed to re-arm dead man's switch: {:?}", e);
+                }
+                worker_signal.wait(rearm_interval);
+            }
+            let _ = self.cancel_all_orders_after(0);
+        });
+
+        DeadMansSwitchGuard {
+            signal,
+            worker: Some(worker),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::kraken::{KrakenClient, KrakenError};
+use std::collections::HashMap;
+
+/// Tradable-pair metadata from the public `AssetPairs` endpoint, cached
+/// after first fetch since it rarely changes within a process lifetime.
+#[derive(Debug, Clone)]
+pub struct PairInfo {
+    pub altname: String,
+    pub wsname: String,
+    pub base: String,
+    pub quote: String,
+    pub price_decimals: u32,
+    pub volume_decimals: u32,
+    pub ordermin: f64,
+}
+
+impl KrakenClient {
+    /// Fetches (and caches) metadata for every tradable pair, keyed by
+    /// Kraken's canonical pair name (e.g. `"XXBTZUSD"`).
+    pub fn get_asset_pairs(&self) -> Result<HashMap<String, PairInfo>, KrakenError> {
+        if let Some(cached) = self.asset_pairs.get() {
+            return Ok(cached.clone());
+        }
+
+        let result = self.query("AssetPairs", serde_json::json!({}))?;
+        let entries = result
+            .as_object()
+            .ok_or_else(|| KrakenError::ParseError("Expected AssetPairs result to be an object".to_string()))?;
+
+        let mut pairs = HashMap::new();
+        for (key, value) in entries.iter() {
+            let altname = value
+                .get("altname")
+                .and_then(|v| v.as_str())
+                .unwrap_or(key)
+                .to_string();
+            let wsname = value
+                .get("wsname")
+                .and_then(|v| v.as_str())
+                .unwrap_or(&altname)
+                .to_string();
+
+            pairs.insert(
+                key.clone(),
+                PairInfo {
+                    wsname,
+                    altname,
+                    base: value.get("base").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    quote: value.get("quote").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    price_decimals: value.get("pair_decimals").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+                    volume_decimals: value.get("lot_decimals").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+                    ordermin: value
+                        .get("ordermin")
+                        .and_then(|v| v.as_str())
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(0.0),
+                },
+            );
+        }
+
+        // Another thread may have populated the cache concurrently; either
+        // value is equally valid, so ignore a losing `set`.
+        let _ = self.asset_pairs.set(pairs.clone());
+        Ok(pairs)
+    }
+
+    /// Resolves a loosely-typed query like `"btcusd"` or `"bitcoin"` to a
+    /// canonical pair by fuzzy/substring matching against `wsname` and
+    /// `altname`, after aliasing common names to Kraken's pair-code
+    /// conventions (e.g. `btc`/`bitcoin` -> `xbt`). Ties (e.g. `"bitcoin"`
+    /// matching every XBT quote pair) are broken by preferring an exact
+    /// `wsname`/`altname` match, then by the canonical pair key, so the
+    /// result is deterministic instead of depending on `HashMap` order.
+    pub fn find_pair(&self, query: &str) -> Result<Option<PairInfo>, KrakenError> {
+        let pairs = self.get_asset_pairs()?;
+        let needle = normalize(query);
+
+        let mut candidates: Vec<(&String, &PairInfo)> = pairs
+            .iter()
+            .filter(|(_, pair)| normalize(&pair.wsname).contains(&needle) || normalize(&pair.altname).contains(&needle))
+            .collect();
+
+        candidates.sort_by(|(key_a, pair_a), (key_b, pair_b)| {
+            let exact_a = normalize(&pair_a.wsname) == needle || normalize(&pair_a.altname) == needle;
+            let exact_b = normalize(&pair_b.wsname) == needle || normalize(&pair_b.altname) == needle;
+            exact_b.cmp(&exact_a).then_with(|| key_a.cmp(key_b))
+        });
+
+        Ok(candidates.into_iter().next().map(|(_, pair)| pair.clone()))
+    }
+}
+
+/// Common name/symbol aliases for Kraken's pair-code conventions, applied in
+/// order as substring replacements on the normalized symbol.
+const ALIASES: &[(&str, &str)] = &[("bitcoin", "xbt"), ("btc", "xbt")];
+
+fn normalize(symbol: &str) -> String {
+    let cleaned: String = symbol
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    ALIASES
+        .iter()
+        .fold(cleaned, |acc, (alias, canonical)| acc.replace(alias, canonical))
+}
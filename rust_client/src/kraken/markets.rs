@@ -1,4 +1,5 @@
 use crate::kraken::{KrakenClient, KrakenError};
+use crate::types::{now_timestamp, BboMsg, OrderBookMsg, Side, TradeMsg};
 use std::collections::HashMap;
 use serde::Deserialize;
 
@@ -20,53 +21,45 @@ where
     }
 }
 
+/// Same string-or-float tolerance as `deserialize_number_from_string`, but
+/// for a `serde_json::Value` already in hand rather than during `Deserialize`.
+fn deserialize_number_from_str(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::String(s) => s.parse().ok(),
+        serde_json::Value::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
 impl KrakenClient {
     fn get_ticker(&self, pair: &str) -> Result<HashMap<String, serde_json::Value>, KrakenError> {
-        let url = format!(
-            "https://api.kraken.com/0/public/Ticker?pair={}",
-            pair
-        );
-
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .map_err(KrakenError::HttpError)?;
-
-        let json: serde_json::Value = response.json().map_err(|e| {
-            KrakenError::ParseError(format!("Failed to parse JSON: {}", e.to_string()))
-        })?;
-
-        if let Some(result) = json["result"].as_object() {
-            let mut result_map = HashMap::new();
-            for (key, value) in result.iter() {
-                result_map.insert(key.clone(), value.clone());
-            }
-            Ok(result_map)
-        } else {
-            Err(KrakenError::ParseError("Missing result field".into()))
-        }
+        let result = self.query("Ticker", serde_json::json!({ "pair": pair }))?;
+
+        result
+            .as_object()
+            .map(|obj| obj.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+            .ok_or_else(|| KrakenError::ParseError("Missing result field".into()))
     }
 
     pub fn get_bid(&self, pair: &str) -> Result<f64, KrakenError> {
         let data = self.get_ticker(pair)?;
         let pair_data = data.values().next().ok_or_else(|| KrakenError::ParseError("Missing pair data".to_string()))?;
-        
+
         let bid = pair_data["b"][0]
             .as_str()
             .ok_or_else(|| KrakenError::ParseError("Missing bid".into()))?;
-        
+
         Ok(bid.parse().unwrap_or(0.0))
     }
 
     pub fn get_ask(&self, pair: &str) -> Result<f64, KrakenError> {
         let data = self.get_ticker(pair)?;
         let pair_data = data.values().next().ok_or_else(|| KrakenError::ParseError("Missing pair data".to_string()))?;
-        
+
         let ask = pair_data["a"][0]
             .as_str()
             .ok_or_else(|| KrakenError::ParseError("Missing ask".into()))?;
-        
+
         Ok(ask.parse().unwrap_or(0.0))
     }
 
@@ -76,15 +69,57 @@ impl KrakenClient {
         Ok(ask - bid)
     }
 
-    pub fn get_recent_trades(&self, _ticker: &str) -> Result<Vec<(f64, f64, f64, String, String, String)>, KrakenError> {
-        // Implementation would go here
-        unimplemented!()
+    /// Fetches recent trades for `pair` via the public `Trades` endpoint.
+    /// `since` is an optional trade id/timestamp cursor from a previous
+    /// call's returned `last` value, used to page forward through history.
+    /// Returns the trades plus the `last` cursor for the next page.
+    pub fn get_recent_trades(
+        &self,
+        pair: &str,
+        since: Option<&str>,
+    ) -> Result<(Vec<(f64, f64, f64, String, String, String)>, String), KrakenError> {
+        let mut params = serde_json::json!({ "pair": pair });
+        if let Some(since) = since {
+            params["since"] = serde_json::Value::String(since.to_string());
+        }
+
+        let result = self.query("Trades", params)?;
+
+        let pair_data = result
+            .as_object()
+            .and_then(|obj| obj.iter().find(|(k, _)| k.as_str() != "last"))
+            .map(|(_, v)| v)
+            .ok_or_else(|| KrakenError::ParseError("Missing pair data".to_string()))?;
+
+        let trades = pair_data
+            .as_array()
+            .ok_or_else(|| KrakenError::ParseError("Missing trades array".to_string()))?
+            .iter()
+            .filter_map(|entry| {
+                let entry = entry.as_array()?;
+                let price = deserialize_number_from_str(entry.get(0)?)?;
+                let volume = deserialize_number_from_str(entry.get(1)?)?;
+                let time = entry.get(2)?.as_f64()?;
+                let buy_sell = entry.get(3)?.as_str()?.to_string();
+                let market_limit = entry.get(4)?.as_str()?.to_string();
+                let misc = entry.get(5).and_then(|v| v.as_str()).unwrap_or("").to_string();
+                Some((price, volume, time, buy_sell, market_limit, misc))
+            })
+            .collect();
+
+        let last = result
+            .get("last")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        Ok((trades, last))
     }
 
     pub fn get_orderbook(&self, pair: &str) -> Result<(Vec<f64>, Vec<f64>), KrakenError> {
         let data = self.get_ticker(pair)?;
         let pair_data = data.values().next().ok_or_else(|| KrakenError::ParseError("Missing pair data".to_string()))?;
-        
+
         let bids: Vec<f64> = pair_data["b"]
             .as_array()
             .ok_or_else(|| KrakenError::ParseError("Missing bids".into()))?
@@ -101,4 +136,83 @@ impl KrakenClient {
 
         Ok((bids, asks))
     }
-}
\ No newline at end of file
+
+    /// Normalized top-of-book snapshot, exchange-tagged so callers can
+    /// compare Kraken and Binance directly.
+    pub fn get_bbo(&self, pair: &str) -> Result<BboMsg, KrakenError> {
+        let data = self.get_ticker(pair)?;
+        let pair_data = data.values().next().ok_or_else(|| KrakenError::ParseError("Missing pair data".to_string()))?;
+
+        let level = |side: &str, index: usize| -> Result<f64, KrakenError> {
+            pair_data[side][index]
+                .as_str()
+                .and_then(|s| s.parse::<f64>().ok())
+                .ok_or_else(|| KrakenError::ParseError(format!("Missing {}[{}]", side, index)))
+        };
+
+        Ok(BboMsg {
+            exchange: "kraken".to_string(),
+            symbol: pair.to_string(),
+            timestamp: now_timestamp(),
+            bid_price: level("b", 0)?,
+            bid_quantity: level("b", 2)?,
+            ask_price: level("a", 0)?,
+            ask_quantity: level("a", 2)?,
+        })
+    }
+
+    /// Normalized order book snapshot via the public `Depth` endpoint, with
+    /// full (price, quantity) levels rather than prices alone.
+    pub fn get_orderbook_msg(&self, pair: &str) -> Result<OrderBookMsg, KrakenError> {
+        let result = self.query("Depth", serde_json::json!({ "pair": pair }))?;
+        let pair_data = result
+            .as_object()
+            .and_then(|obj| obj.values().next())
+            .ok_or_else(|| KrakenError::ParseError("Missing pair data".to_string()))?;
+
+        let parse_side = |levels: &serde_json::Value| -> Result<Vec<(f64, f64)>, KrakenError> {
+            levels
+                .as_array()
+                .ok_or_else(|| KrakenError::ParseError("Missing book levels".to_string()))?
+                .iter()
+                .map(|level| {
+                    let price = level[0]
+                        .as_str()
+                        .and_then(|s| s.parse::<f64>().ok())
+                        .ok_or_else(|| KrakenError::ParseError("Missing level price".to_string()))?;
+                    let qty = level[1]
+                        .as_str()
+                        .and_then(|s| s.parse::<f64>().ok())
+                        .ok_or_else(|| KrakenError::ParseError("Missing level quantity".to_string()))?;
+                    Ok((price, qty))
+                })
+                .collect()
+        };
+
+        Ok(OrderBookMsg {
+            exchange: "kraken".to_string(),
+            symbol: pair.to_string(),
+            timestamp: now_timestamp(),
+            bids: parse_side(&pair_data["bids"])?,
+            asks: parse_side(&pair_data["asks"])?,
+        })
+    }
+
+    /// Normalized recent-trades view over [`KrakenClient::get_recent_trades`].
+    pub fn get_trade_msgs(&self, pair: &str, since: Option<&str>) -> Result<(Vec<TradeMsg>, String), KrakenError> {
+        let (trades, last) = self.get_recent_trades(pair, since)?;
+        let msgs = trades
+            .into_iter()
+            .map(|(price, volume, time, buy_sell, _market_limit, _misc)| TradeMsg {
+                exchange: "kraken".to_string(),
+                symbol: pair.to_string(),
+                timestamp: time,
+                side: if buy_sell == "b" { Side::Buy } else { Side::Sell },
+                price,
+                quantity: volume,
+            })
+            .collect();
+
+        Ok((msgs, last))
+    }
+}
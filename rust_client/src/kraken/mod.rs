@@ -5,8 +5,22 @@ use std::path::Path;
 use hmac::{Hmac, Mac};
 use sha2::{Sha256, Sha512, Digest};
 use base64::{engine::general_purpose::STANDARD, Engine};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::kraken::assets::PairInfo;
+
+const NONCE_FILE: &str = "config/.nonce";
+
+/// Process-wide nonce counter, shared by every `KrakenClient` instance
+/// rather than stored per-instance: callers like the `#[pyfunction]`
+/// wrappers in `lib.rs` construct a fresh `KrakenClient` on every call, and a
+/// per-instance atomic wouldn't guard two calls against each other at all.
+/// Seeded once from the persisted nonce file on first use.
+static NONCE: OnceLock<AtomicU64> = OnceLock::new();
+
 #[derive(Debug)]
 pub enum KrakenError {
     HttpError(reqwest::Error),
@@ -28,9 +42,10 @@ struct KrakenConfig {
 
 pub struct KrakenClient {
     client: Client,
-    pair: String,
+    pub(crate) pair: String,
     api_key: String,
     api_secret: String,
+    pub(crate) asset_pairs: OnceLock<HashMap<String, PairInfo>>,
 }
 
 impl KrakenClient {
@@ -44,15 +59,54 @@ impl KrakenClient {
             pair: config.kraken.default_pair,
             api_key: config.kraken.api_key,
             api_secret: config.kraken.api_secret,
+            asset_pairs: OnceLock::new(),
         }
     }
 
+    fn load_persisted_nonce() -> u64 {
+        fs::read_to_string(NONCE_FILE)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
+    fn persist_nonce(nonce: u64) {
+        // Best-effort: a failed write just means a restart may briefly
+        // re-derive the nonce from the clock instead of the last-issued value.
+        let _ = fs::write(NONCE_FILE, nonce.to_string());
+    }
+
+    /// Returns a nonce that is strictly greater than every nonce issued by
+    /// this process before, even across restarts, several calls landing in
+    /// the same microsecond, or separate `KrakenClient` instances racing each
+    /// other, so Kraken never rejects a private call for a non-increasing
+    /// nonce. Backed by a process-wide counter rather than a per-instance
+    /// one: every `#[pyfunction]` in `lib.rs` builds a fresh `KrakenClient`
+    /// per call, so an instance-local atomic wouldn't guard those calls
+    /// against each other at all. Seeded from microsecond resolution so
+    /// back-to-back calls get distinct clock-derived values rather than
+    /// relying on the `+1` fallback alone.
     pub fn generate_nonce(&self) -> String {
-        SystemTime::now()
+        let nonce = NONCE.get_or_init(|| AtomicU64::new(Self::load_persisted_nonce()));
+        let now_us = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
-            .as_millis()
-            .to_string()
+            .as_micros() as u64;
+
+        let mut next = now_us;
+        loop {
+            let last = nonce.load(Ordering::SeqCst);
+            next = std::cmp::max(now_us, last + 1);
+            if nonce
+                .compare_exchange(last, next, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                break;
+            }
+        }
+
+        Self::persist_nonce(next);
+        next.to_string()
     }
 
     pub fn create_signature_message(&self, path: &str, nonce: &str, body_str: &str) -> Vec<u8> {
@@ -75,7 +129,121 @@ impl KrakenClient {
         let result = mac.finalize().into_bytes();
         Ok(STANDARD.encode(result))
     }
+
+    /// Routes `endpoint` to the public or private REST surface based on
+    /// `PUBLIC_ENDPOINTS`, handling nonce injection, signing, and the
+    /// `error`/`result` envelope in one place. New endpoints only need a
+    /// params object and a spot in that table (if public) - no copy-pasted
+    /// request plumbing.
+    pub fn query(&self, endpoint: &str, params: serde_json::Value) -> Result<serde_json::Value, KrakenError> {
+        if PUBLIC_ENDPOINTS.contains(&endpoint) {
+            self.query_public(endpoint, params)
+        } else {
+            self.query_private(endpoint, params)
+        }
+    }
+
+    /// Calls a public (unauthenticated) Kraken REST endpoint, e.g. `"Ticker"`,
+    /// and returns the unwrapped `result` object.
+    fn query_public(&self, endpoint: &str, params: serde_json::Value) -> Result<serde_json::Value, KrakenError> {
+        let query = to_query_string(&params);
+        let url = if query.is_empty() {
+            format!("{}/0/public/{}", API_BASE, endpoint)
+        } else {
+            format!("{}/0/public/{}?{}", API_BASE, endpoint, query)
+        };
+
+        let response = self.client.get(&url).send().map_err(KrakenError::HttpError)?;
+        let json: serde_json::Value = response.json()
+            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
+
+        Self::unwrap_result(json)
+    }
+
+    /// Calls a private (signed) Kraken REST endpoint, e.g. `"Balance"`,
+    /// auto-injecting the nonce and computing the signature, and returns the
+    /// unwrapped `result` object.
+    fn query_private(&self, endpoint: &str, mut params: serde_json::Value) -> Result<serde_json::Value, KrakenError> {
+        if params.is_null() {
+            params = serde_json::json!({});
+        }
+        let nonce = self.generate_nonce();
+        params
+            .as_object_mut()
+            .ok_or_else(|| KrakenError::ParseError("params must be a JSON object".to_string()))?
+            .insert("nonce".to_string(), serde_json::Value::String(nonce.clone()));
+
+        let body = to_query_string(&params);
+        let path = format!("/0/private/{}", endpoint);
+        let url = format!("{}{}", API_BASE, path);
+
+        let message = self.create_signature_message(&path, &nonce, &body);
+        let signature = self.sign_message(&message)?;
+
+        let response = self.client
+            .post(&url)
+            .header("API-Key", &self.api_key)
+            .header("API-Sign", signature)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .map_err(KrakenError::HttpError)?;
+
+        let json: serde_json::Value = response.json()
+            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
+
+        Self::unwrap_result(json)
+    }
+
+    fn unwrap_result(json: serde_json::Value) -> Result<serde_json::Value, KrakenError> {
+        if let Some(errors) = json.get("error").and_then(serde_json::Value::as_array) {
+            if !errors.is_empty() {
+                return Err(KrakenError::ParseError(format!("Kraken API error: {:?}", errors)));
+            }
+        }
+
+        json.get("result")
+            .cloned()
+            .ok_or_else(|| KrakenError::MissingField("result".to_string()))
+    }
+}
+
+const API_BASE: &str = "https://api.kraken.com";
+
+/// Public (unauthenticated) endpoints that `query` should route to
+/// `query_public`; anything not listed here is treated as private.
+const PUBLIC_ENDPOINTS: &[&str] = &[
+    "Time",
+    "SystemStatus",
+    "Assets",
+    "AssetPairs",
+    "Ticker",
+    "OHLC",
+    "Depth",
+    "Trades",
+    "Spread",
+];
+
+fn to_query_string(params: &serde_json::Value) -> String {
+    match params.as_object() {
+        Some(obj) => obj
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, value_to_query_part(v)))
+            .collect::<Vec<String>>()
+            .join("&"),
+        None => String::new(),
+    }
+}
+
+fn value_to_query_part(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
 }
 
 pub mod account;
-pub mod markets;
\ No newline at end of file
+pub mod assets;
+pub mod markets;
+pub mod orderbook;
+pub mod streaming;
\ No newline at end of file
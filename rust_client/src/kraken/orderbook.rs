@@ -0,0 +1,258 @@
+use crate::kraken::KrakenError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Bid,
+    Ask,
+}
+
+/// One book level as it arrived on the wire: a parsed `f64` for sorting and
+/// comparisons, alongside the exact price/volume strings Kraken sent. The
+/// checksum is defined over those wire strings, not a reformatted float, so
+/// both are kept side by side for the lifetime of the level.
+#[derive(Debug, Clone)]
+struct Level {
+    price: f64,
+    price_str: String,
+    volume: f64,
+    volume_str: String,
+}
+
+/// A live local order book kept in sync with Kraken's `book` WebSocket feed:
+/// an initial snapshot followed by `a`/`b` deltas, reconciled against the
+/// feed's CRC32 `c` checksum on every update.
+pub struct OrderBook {
+    depth: usize,
+    bids: Vec<Level>,
+    asks: Vec<Level>,
+}
+
+impl OrderBook {
+    pub fn new(depth: usize) -> Self {
+        OrderBook {
+            depth,
+            bids: Vec::new(),
+            asks: Vec::new(),
+        }
+    }
+
+    pub fn bids(&self) -> Vec<(f64, f64)> {
+        self.bids.iter().map(|level| (level.price, level.volume)).collect()
+    }
+
+    pub fn asks(&self) -> Vec<(f64, f64)> {
+        self.asks.iter().map(|level| (level.price, level.volume)).collect()
+    }
+
+    pub fn top_of_book(&self) -> Option<((f64, f64), (f64, f64))> {
+        let bid = self.bids.first()?;
+        let ask = self.asks.first()?;
+        Some(((bid.price, bid.volume), (ask.price, ask.volume)))
+    }
+
+    /// Feeds one `book` channel message (snapshot or delta) into the book.
+    /// Accepts either the raw v1 frame (`[channelID, {...}, ..., "book-10",
+    /// "XBT/USD"]`, with two update objects when bids and asks changed in
+    /// the same tick) or an already-extracted `{...}` payload object.
+    /// Returns `false` (after dropping all local state) if the message
+    /// carried a checksum that didn't match, signalling the caller to
+    /// resubscribe and resnapshot.
+    pub fn ingest(&mut self, payload: &serde_json::Value) -> Result<bool, KrakenError> {
+        let merged;
+        let payload = match payload.as_array() {
+            Some(frame) => {
+                merged = merge_book_frame(frame)?;
+                &merged
+            }
+            None => payload,
+        };
+
+        if let (Some(ask_levels), Some(bid_levels)) = (payload.get("as"), payload.get("bs")) {
+            self.asks = parse_levels(ask_levels)?;
+            self.bids = parse_levels(bid_levels)?;
+            sort_and_truncate(Side::Ask, &mut self.asks, self.depth);
+            sort_and_truncate(Side::Bid, &mut self.bids, self.depth);
+            return Ok(true);
+        }
+
+        if let Some(ask_levels) = payload.get("a") {
+            for level in parse_levels(ask_levels)? {
+                self.apply_delta(Side::Ask, level);
+            }
+        }
+        if let Some(bid_levels) = payload.get("b") {
+            for level in parse_levels(bid_levels)? {
+                self.apply_delta(Side::Bid, level);
+            }
+        }
+
+        match payload.get("c").and_then(|c| c.as_str()) {
+            Some(checksum_str) => {
+                let expected: u32 = checksum_str
+                    .parse()
+                    .map_err(|_| KrakenError::ParseError("Invalid checksum field".to_string()))?;
+                let valid = self.checksum() == expected;
+                if !valid {
+                    self.bids.clear();
+                    self.asks.clear();
+                }
+                Ok(valid)
+            }
+            None => Ok(true),
+        }
+    }
+
+    fn apply_delta(&mut self, side: Side, level: Level) {
+        let levels = match side {
+            Side::Bid => &mut self.bids,
+            Side::Ask => &mut self.asks,
+        };
+
+        levels.retain(|existing| existing.price != level.price);
+        if level.volume != 0.0 {
+            levels.push(level);
+        }
+        sort_and_truncate(side, levels, self.depth);
+    }
+
+    /// Kraken's checksum: top 10 asks ascending, then top 10 bids descending,
+    /// each level's exact wire price/volume string with the decimal point
+    /// and leading zeros stripped, concatenated and CRC32'd.
+    fn checksum(&self) -> u32 {
+        let mut message = String::new();
+        for level in self.asks.iter().take(10) {
+            message.push_str(&checksum_component(&level.price_str));
+            message.push_str(&checksum_component(&level.volume_str));
+        }
+        for level in self.bids.iter().take(10) {
+            message.push_str(&checksum_component(&level.price_str));
+            message.push_str(&checksum_component(&level.volume_str));
+        }
+        crc32(message.as_bytes())
+    }
+}
+
+/// Merges the update object(s) out of a raw v1 `book` frame. The frame is
+/// `[channelID, {...}, "book-10", "XBT/USD"]` normally, or
+/// `[channelID, {a:...}, {b:...,c:...}, "book-10", "XBT/USD"]` when both
+/// sides update in the same tick - everything between the leading channel
+/// ID and the trailing `(channelName, pair)` pair is an update object, so
+/// they're merged into one before the rest of `ingest` runs.
+fn merge_book_frame(frame: &[serde_json::Value]) -> Result<serde_json::Value, KrakenError> {
+    if frame.len() < 4 {
+        return Err(KrakenError::ParseError("Malformed book frame".to_string()));
+    }
+
+    let mut merged = serde_json::Map::new();
+    for entry in &frame[1..frame.len() - 2] {
+        if let Some(obj) = entry.as_object() {
+            for (key, value) in obj {
+                merged.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Ok(serde_json::Value::Object(merged))
+}
+
+fn sort_and_truncate(side: Side, levels: &mut Vec<Level>, depth: usize) {
+    match side {
+        Side::Ask => levels.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap()),
+        Side::Bid => levels.sort_by(|a, b| b.price.partial_cmp(&a.price).unwrap()),
+    }
+    levels.truncate(depth);
+}
+
+fn parse_levels(value: &serde_json::Value) -> Result<Vec<Level>, KrakenError> {
+    value
+        .as_array()
+        .ok_or_else(|| KrakenError::ParseError("Expected an array of book levels".to_string()))?
+        .iter()
+        .map(|level| {
+            let price_str = level[0]
+                .as_str()
+                .ok_or_else(|| KrakenError::ParseError("Missing level price".to_string()))?;
+            let volume_str = level[1]
+                .as_str()
+                .ok_or_else(|| KrakenError::ParseError("Missing level volume".to_string()))?;
+            let price = price_str
+                .parse()
+                .map_err(|_| KrakenError::ParseError("Invalid level price".to_string()))?;
+            let volume = volume_str
+                .parse()
+                .map_err(|_| KrakenError::ParseError("Invalid level volume".to_string()))?;
+            Ok(Level {
+                price,
+                price_str: price_str.to_string(),
+                volume,
+                volume_str: volume_str.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Strips the decimal point and leading zeros from a level's exact wire
+/// string, per Kraken's checksum spec.
+fn checksum_component(raw: &str) -> String {
+    let digits_only: String = raw.chars().filter(|&c| c != '.').collect();
+    let trimmed = digits_only.trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Standard CRC-32 (IEEE 802.3 / zlib) — no external crate needed for
+/// something this small.
+fn crc32(data: &[u8]) -> u32 {
+    const POLY: u32 = 0xEDB88320;
+    let mut crc: u32 = 0xFFFFFFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLY & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checksum vector: 3 ask levels / 2 bid levels run through Kraken's
+    /// documented algorithm (wire strings, decimal point and leading zeros
+    /// stripped, concatenated asks-ascending-then-bids-descending, CRC32'd),
+    /// cross-checked against a reference CRC32 implementation (Python's
+    /// `zlib.crc32`) to pin this hand-rolled `crc32`/`checksum_component`
+    /// against a known-good value instead of only self-consistency.
+    #[test]
+    fn checksum_matches_known_vector() {
+        let mut book = OrderBook::new(10);
+        let payload = serde_json::json!({
+            "as": [
+                ["5541.30000", "2.50700000", "1616663113.395822"],
+                ["5541.80000", "0.33000000", "1616663113.395823"],
+                ["5542.10000", "0.10000000", "1616663113.395824"]
+            ],
+            "bs": [
+                ["5541.20000", "1.52900000", "1616663113.395825"],
+                ["5538.70000", "0.72500000", "1616663113.395826"]
+            ]
+        });
+
+        book.ingest(&payload).unwrap();
+
+        assert_eq!(book.checksum(), 3137012397);
+    }
+
+    /// `checksum_component` works on the original wire string, not a
+    /// reformatted float: only the decimal point and leading zeros go, and
+    /// trailing zeros a float formatter would drop are kept.
+    #[test]
+    fn checksum_component_strips_point_and_leading_zeros_only() {
+        assert_eq!(checksum_component("0.00000500"), "500");
+        assert_eq!(checksum_component("5541.30000"), "554130000");
+    }
+}
@@ -0,0 +1,640 @@
+use crate::kraken::account::OpenOrder;
+use crate::kraken::{KrakenClient, KrakenError};
+use crate::rate::Rate;
+use std::collections::{HashMap, HashSet};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{connect, Message, WebSocket};
+use url::Url;
+
+const KRAKEN_WS_V2_URL: &str = "wss://ws.kraken.com/v2";
+const KRAKEN_WS_V1_URL: &str = "wss://ws.kraken.com";
+const READ_TIMEOUT: Duration = Duration::from_millis(500);
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+
+/// A single connection attempt either dies because the socket/handshake
+/// failed (worth a backed-off reconnect) or because one frame didn't parse
+/// (worth logging and skipping, not tearing down the connection).
+enum StreamError {
+    Connection(String),
+    Parse(String),
+}
+
+fn log_stream_error(err: &StreamError) {
+    match err {
+        StreamError::Connection(reason) => eprintln!("stream connection error: {}", reason),
+        StreamError::Parse(reason) => eprintln!("dropping malformed frame: {}", reason),
+    }
+}
+
+/// Drives a reconnect loop: `attempt` makes one connection attempt and
+/// returns its `StreamError` plus whether the connection was ever actually
+/// established (so a blip after a long healthy run doesn't count against
+/// the retry budget). Retries `StreamError::Connection` with exponential
+/// backoff, resetting on success; after `MAX_RECONNECT_ATTEMPTS` consecutive
+/// failures to even connect, gives up and records `reason` into `failure` so
+/// `wait_for_update` can surface a terminal error instead of blocking
+/// forever. `StreamError::Parse` is logged and otherwise ignored - it never
+/// reaches here from a well-behaved `attempt` since individual frame errors
+/// are absorbed inside the stream loop itself.
+fn run_reconnect_loop(
+    shutdown: &AtomicBool,
+    failure: &Mutex<Option<String>>,
+    signal: &Condvar,
+    mut attempt: impl FnMut() -> (StreamError, bool),
+) {
+    let mut backoff = INITIAL_BACKOFF;
+    let mut consecutive_connect_failures = 0u32;
+
+    while !shutdown.load(Ordering::Relaxed) {
+        let (err, connected) = attempt();
+        if shutdown.load(Ordering::Relaxed) {
+            return;
+        }
+
+        match err {
+            StreamError::Connection(reason) => {
+                if connected {
+                    consecutive_connect_failures = 0;
+                    backoff = INITIAL_BACKOFF;
+                } else {
+                    consecutive_connect_failures += 1;
+                }
+
+                if consecutive_connect_failures >= MAX_RECONNECT_ATTEMPTS {
+                    let message = format!(
+                        "giving up after {} consecutive failed connection attempts: {}",
+                        consecutive_connect_failures, reason
+                    );
+                    eprintln!("stream permanently failed: {}", message);
+                    *failure.lock().expect("feed mutex poisoned") = Some(message);
+                    signal.notify_all();
+                    return;
+                }
+
+                log_stream_error(&StreamError::Connection(reason));
+                thread::sleep(backoff);
+                backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+            }
+            StreamError::Parse(reason) => log_stream_error(&StreamError::Parse(reason)),
+        }
+    }
+}
+
+struct Shared {
+    rate: Mutex<Option<Rate>>,
+    /// Set when `rate` is replaced, cleared when `wait_for_update` returns
+    /// it; only changed while holding the `rate` lock.
+    fresh: AtomicBool,
+    signal: Condvar,
+    shutdown: AtomicBool,
+}
+
+/// Streams a live bid/ask snapshot for a single pair over the Kraken v2
+/// WebSocket API, reconnecting and re-subscribing on any disconnect.
+pub struct PriceTicker {
+    shared: Arc<Shared>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl PriceTicker {
+    /// Opens a persistent connection and subscribes to ticker updates for `pair`
+    /// (e.g. "BTC/USD"). Runs the socket on a background thread.
+    pub fn connect(pair: &str) -> Result<Self, KrakenError> {
+        let shared = Arc::new(Shared {
+            rate: Mutex::new(None),
+            fresh: AtomicBool::new(false),
+            signal: Condvar::new(),
+            shutdown: AtomicBool::new(false),
+        });
+
+        let worker_shared = Arc::clone(&shared);
+        let pair = pair.to_string();
+        let worker = thread::spawn(move || Self::run(pair, worker_shared));
+
+        Ok(PriceTicker {
+            shared,
+            worker: Some(worker),
+        })
+    }
+
+    /// Blocks until a new bid/ask snapshot arrives and returns it.
+    pub fn wait_for_update(&self) -> Rate {
+        let mut guard = self.shared.rate.lock().expect("ticker mutex poisoned");
+        loop {
+            if self.shared.fresh.swap(false, Ordering::Relaxed) {
+                if let Some(rate) = *guard {
+                    return rate;
+                }
+            }
+            guard = self.shared.signal.wait(guard).expect("ticker mutex poisoned");
+        }
+    }
+
+    /// Returns the most recent snapshot without waiting for a new one, if any
+    /// has arrived yet.
+    pub fn latest(&self) -> Option<Rate> {
+        *self.shared.rate.lock().expect("ticker mutex poisoned")
+    }
+
+    fn run(pair: String, shared: Arc<Shared>) {
+        while !shared.shutdown.load(Ordering::Relaxed) {
+            if let Err(_e) = Self::stream_once(&pair, &shared) {
+                // Connection or parse failure: back off briefly and re-subscribe.
+            }
+            if shared.shutdown.load(Ordering::Relaxed) {
+                return;
+            }
+            thread::sleep(RECONNECT_DELAY);
+        }
+    }
+
+    fn stream_once(pair: &str, shared: &Arc<Shared>) -> Result<(), KrakenError> {
+        let url = Url::parse(KRAKEN_WS_V2_URL)
+            .map_err(|e| KrakenError::ParseError(e.to_string()))?;
+        let (mut socket, _) = connect(url)
+            .map_err(|e| KrakenError::ParseError(format!("connect failed: {}", e)))?;
+        set_read_timeout(&mut socket, Some(READ_TIMEOUT));
+
+        let subscribe = serde_json::json!({
+            "method": "subscribe",
+            "params": { "channel": "ticker", "symbol": [pair] }
+        });
+        socket
+            .write_message(Message::Text(subscribe.to_string()))
+            .map_err(|e| KrakenError::ParseError(format!("subscribe failed: {}", e)))?;
+
+        while !shared.shutdown.load(Ordering::Relaxed) {
+            match socket.read_message() {
+                Ok(Message::Text(text)) => {
+                    if let Some(rate) = parse_ticker_frame(&text) {
+                        let mut guard = shared.rate.lock().expect("ticker mutex poisoned");
+                        *guard = Some(rate);
+                        shared.fresh.store(true, Ordering::Relaxed);
+                        drop(guard);
+                        shared.signal.notify_all();
+                    }
+                }
+                Ok(Message::Close(_)) => return Ok(()),
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(ref e))
+                    if e.kind() == std::io::ErrorKind::WouldBlock
+                        || e.kind() == std::io::ErrorKind::TimedOut =>
+                {
+                    continue;
+                }
+                Err(e) => return Err(KrakenError::ParseError(format!("read failed: {}", e))),
+            }
+        }
+
+        let _ = socket.close(None);
+        Ok(())
+    }
+}
+
+impl Drop for PriceTicker {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn set_read_timeout(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, timeout: Option<Duration>) {
+    match socket.get_ref() {
+        MaybeTlsStream::Plain(stream) => {
+            let _ = stream.set_read_timeout(timeout);
+        }
+        #[cfg(feature = "native-tls")]
+        MaybeTlsStream::NativeTls(stream) => {
+            let _ = stream.get_ref().set_read_timeout(timeout);
+        }
+        _ => {}
+    }
+}
+
+/// Both the initial snapshot and subsequent deltas carry a full `bid`/`ask`
+/// on the ticker channel, so both are handled identically here.
+fn parse_ticker_frame(text: &str) -> Option<Rate> {
+    let value: serde_json::Value = serde_json::from_str(text).ok()?;
+
+    if value.get("channel")?.as_str()? != "ticker" {
+        return None;
+    }
+
+    let entry = value.get("data")?.as_array()?.first()?;
+    let bid = entry.get("bid")?.as_f64()?;
+    let ask = entry.get("ask")?.as_f64()?;
+    Some(Rate { bid, ask })
+}
+
+struct MultiShared {
+    /// Latest known rate per pair - overwritten in place, never removed, so
+    /// `latest()` always has an answer once a pair has updated at least once.
+    rates: Mutex<HashMap<String, Rate>>,
+    /// Pairs with an update since the last `wait_for_update` consumed them.
+    /// Kept separate from `rates` so waiting for (and consuming) an update
+    /// doesn't erase the cached value `latest()` reads.
+    pending: Mutex<HashSet<String>>,
+    failure: Mutex<Option<String>>,
+    signal: Condvar,
+    shutdown: AtomicBool,
+}
+
+/// Streams live bid/ask snapshots for several pairs at once over the legacy
+/// Kraken v1 WebSocket API, reconnecting with capped exponential backoff on
+/// any connection drop and absorbing malformed individual frames in place.
+/// Subscribers only ever observe the latest good rate or a terminal failure
+/// via [`is_failed`](TickerFeed::is_failed) - never a raw connection hiccup.
+/// Prefer [`PriceTicker`] for a single v2 pair; this exists for callers that
+/// want one socket covering a whole watchlist.
+pub struct TickerFeed {
+    shared: Arc<MultiShared>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl TickerFeed {
+    /// Blocks until any subscribed pair publishes a new snapshot, returning
+    /// `(pair, rate)` for whichever one updated, or the failure reason once
+    /// reconnect attempts have been permanently exhausted.
+    pub fn wait_for_update(&self) -> Result<(String, Rate), String> {
+        let mut guard = self.shared.pending.lock().expect("ticker feed mutex poisoned");
+        loop {
+            if let Some(pair) = guard.iter().next().cloned() {
+                guard.remove(&pair);
+                let rate = self
+                    .shared
+                    .rates
+                    .lock()
+                    .expect("ticker feed mutex poisoned")
+                    .get(&pair)
+                    .copied()
+                    .expect("pending pair missing from rates cache");
+                return Ok((pair, rate));
+            }
+            if let Some(reason) = self.shared.failure.lock().expect("ticker feed mutex poisoned").clone() {
+                return Err(reason);
+            }
+            let (next_guard, _) = self
+                .shared
+                .signal
+                .wait_timeout(guard, READ_TIMEOUT)
+                .expect("ticker feed mutex poisoned");
+            guard = next_guard;
+        }
+    }
+
+    /// Returns the most recent snapshot for `pair` without waiting, if any
+    /// has arrived yet.
+    pub fn latest(&self, pair: &str) -> Option<Rate> {
+        self.shared
+            .rates
+            .lock()
+            .expect("ticker feed mutex poisoned")
+            .get(pair)
+            .copied()
+    }
+
+    /// True once retries are exhausted and the feed will never recover.
+    pub fn is_failed(&self) -> bool {
+        self.shared.failure.lock().expect("ticker feed mutex poisoned").is_some()
+    }
+
+    /// Reconnects with capped exponential backoff after a connection drop,
+    /// and permanently gives up (recording the reason into `shared.failure`)
+    /// after `MAX_RECONNECT_ATTEMPTS` consecutive failures to even
+    /// establish a connection. See [`run_reconnect_loop`].
+    fn run(pairs: Vec<String>, shared: Arc<MultiShared>) {
+        run_reconnect_loop(&shared.shutdown, &shared.failure, &shared.signal, || {
+            Self::stream_once(&pairs, &shared)
+        });
+    }
+
+    /// Runs one connection attempt. Returns the reason the attempt ended and
+    /// whether the socket ever got far enough to subscribe - i.e. whether
+    /// this was a healthy connection that later dropped, versus a failure to
+    /// connect at all.
+    fn stream_once(pairs: &[String], shared: &Arc<MultiShared>) -> (StreamError, bool) {
+        let url = match Url::parse(KRAKEN_WS_V1_URL) {
+            Ok(u) => u,
+            Err(e) => return (StreamError::Connection(format!("bad URL: {}", e)), false),
+        };
+        let (mut socket, _) = match connect(url) {
+            Ok(pair) => pair,
+            Err(e) => return (StreamError::Connection(format!("connect failed: {}", e)), false),
+        };
+        set_read_timeout(&mut socket, Some(READ_TIMEOUT));
+
+        let subscribe = serde_json::json!({
+            "event": "subscribe",
+            "pair": pairs,
+            "subscription": { "name": "ticker" }
+        });
+        if let Err(e) = socket.write_message(Message::Text(subscribe.to_string())) {
+            return (StreamError::Connection(format!("subscribe failed: {}", e)), false);
+        }
+
+        while !shared.shutdown.load(Ordering::Relaxed) {
+            match socket.read_message() {
+                Ok(Message::Text(text)) => match parse_ticker_frame_v1(&text) {
+                    Ok(Some((pair, rate))) => {
+                        shared
+                            .rates
+                            .lock()
+                            .expect("ticker feed mutex poisoned")
+                            .insert(pair.clone(), rate);
+                        shared.pending.lock().expect("ticker feed mutex poisoned").insert(pair);
+                        shared.signal.notify_all();
+                    }
+                    Ok(None) => {}
+                    Err(reason) => log_stream_error(&StreamError::Parse(reason)),
+                },
+                Ok(Message::Close(_)) => {
+                    return (StreamError::Connection("server closed connection".to_string()), true)
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(ref e))
+                    if e.kind() == std::io::ErrorKind::WouldBlock
+                        || e.kind() == std::io::ErrorKind::TimedOut =>
+                {
+                    continue;
+                }
+                Err(e) => return (StreamError::Connection(format!("read failed: {}", e)), true),
+            }
+        }
+
+        let _ = socket.close(None);
+        (StreamError::Connection("shutdown requested".to_string()), true)
+    }
+}
+
+impl Drop for TickerFeed {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// The v1 `ticker` channel publishes each update as a 4-element array:
+/// `[channelID, {"b":["bid",...],"a":["ask",...],...}, "ticker", "XBT/USD"]`.
+/// Heartbeats and subscription-status events use an object shape instead and
+/// are reported as `Ok(None)` rather than an error. A frame that isn't even
+/// valid JSON is the one case worth logging and skipping.
+fn parse_ticker_frame_v1(text: &str) -> Result<Option<(String, Rate)>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|e| format!("{}: {}", e, text))?;
+
+    let frame = match value.as_array() {
+        Some(f) => f,
+        None => return Ok(None),
+    };
+    if frame.len() < 4 || frame.get(2).and_then(|v| v.as_str()) != Some("ticker") {
+        return Ok(None);
+    }
+
+    let parsed = (|| -> Option<(String, Rate)> {
+        let pair = frame.get(3)?.as_str()?.to_string();
+        let payload = frame.get(1)?;
+        let bid = payload.get("b")?.as_array()?.first()?.as_str()?.parse().ok()?;
+        let ask = payload.get("a")?.as_array()?.first()?.as_str()?.parse().ok()?;
+        Some((pair, Rate { bid, ask }))
+    })();
+
+    Ok(parsed)
+}
+
+const KRAKEN_WS_AUTH_URL: &str = "wss://ws-auth.kraken.com";
+
+struct OrderShared {
+    orders: Mutex<HashMap<String, OpenOrder>>,
+    failure: Mutex<Option<String>>,
+    signal: Condvar,
+    shutdown: AtomicBool,
+}
+
+/// Streams authenticated `openOrders` updates, so order state and fills are
+/// pushed live instead of requiring a poll of `get_open_orders_raw` (which
+/// burns a nonce and can miss fills between polls). Reconnects with the same
+/// capped-backoff/terminal-failure behavior as [`TickerFeed`], re-fetching a
+/// fresh token on every reconnect since a token is only valid for 15 minutes.
+pub struct OpenOrdersFeed {
+    shared: Arc<OrderShared>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl OpenOrdersFeed {
+    /// Blocks until an order is added or changes state, returning
+    /// `(txid, order)`, or the failure reason once reconnects are exhausted.
+    pub fn wait_for_update(&self) -> Result<(String, OpenOrder), String> {
+        let mut guard = self.shared.orders.lock().expect("order feed mutex poisoned");
+        loop {
+            if let Some(txid) = guard.keys().next().cloned() {
+                let order = guard.remove(&txid).expect("key just observed");
+                return Ok((txid, order));
+            }
+            if let Some(reason) = self.shared.failure.lock().expect("order feed mutex poisoned").clone() {
+                return Err(reason);
+            }
+            let (next_guard, _) = self
+                .shared
+                .signal
+                .wait_timeout(guard, READ_TIMEOUT)
+                .expect("order feed mutex poisoned");
+            guard = next_guard;
+        }
+    }
+
+    /// True once retries are exhausted and the feed will never recover.
+    pub fn is_failed(&self) -> bool {
+        self.shared.failure.lock().expect("order feed mutex poisoned").is_some()
+    }
+
+    /// Reconnects with capped exponential backoff after a connection drop,
+    /// and permanently gives up (recording the reason into `shared.failure`)
+    /// after `MAX_RECONNECT_ATTEMPTS` consecutive failures to even
+    /// establish a connection. See [`run_reconnect_loop`].
+    fn run(client: Arc<KrakenClient>, shared: Arc<OrderShared>) {
+        run_reconnect_loop(&shared.shutdown, &shared.failure, &shared.signal, || {
+            Self::stream_once(&client, &shared)
+        });
+    }
+
+    /// Runs one connection attempt. Returns the reason the attempt ended and
+    /// whether the socket ever got far enough to subscribe - i.e. whether
+    /// this was a healthy connection that later dropped, versus a failure to
+    /// connect at all.
+    fn stream_once(client: &Arc<KrakenClient>, shared: &Arc<OrderShared>) -> (StreamError, bool) {
+        let token = match client.get_websockets_token() {
+            Ok(t) => t,
+            Err(e) => return (StreamError::Connection(format!("token fetch failed: {:?}", e)), false),
+        };
+
+        let url = match Url::parse(KRAKEN_WS_AUTH_URL) {
+            Ok(u) => u,
+            Err(e) => return (StreamError::Connection(format!("bad URL: {}", e)), false),
+        };
+        let (mut socket, _) = match connect(url) {
+            Ok(pair) => pair,
+            Err(e) => return (StreamError::Connection(format!("connect failed: {}", e)), false),
+        };
+        set_read_timeout(&mut socket, Some(READ_TIMEOUT));
+
+        let subscribe = serde_json::json!({
+            "event": "subscribe",
+            "subscription": { "name": "openOrders", "token": token }
+        });
+        if let Err(e) = socket.write_message(Message::Text(subscribe.to_string())) {
+            return (StreamError::Connection(format!("subscribe failed: {}", e)), false);
+        }
+
+        // Deltas only carry the fields that changed, so each txid's fields
+        // are accumulated here across updates within this connection (a
+        // fresh snapshot arrives on every resubscribe, so nothing needs to
+        // survive a reconnect) until there's enough to deserialize a full
+        // `OpenOrder`.
+        let mut accumulated: HashMap<String, serde_json::Map<String, serde_json::Value>> = HashMap::new();
+
+        while !shared.shutdown.load(Ordering::Relaxed) {
+            match socket.read_message() {
+                Ok(Message::Text(text)) => match parse_open_orders_frame(&text) {
+                    Ok(entries) => {
+                        let mut updated = Vec::new();
+                        for (txid, fields) in entries {
+                            let merged = accumulated.entry(txid.clone()).or_default();
+                            merged.extend(fields);
+                            if let Ok(order) =
+                                serde_json::from_value::<OpenOrder>(serde_json::Value::Object(merged.clone()))
+                            {
+                                updated.push((txid, order));
+                            }
+                        }
+                        if !updated.is_empty() {
+                            let mut guard = shared.orders.lock().expect("order feed mutex poisoned");
+                            for (txid, order) in updated {
+                                guard.insert(txid, order);
+                            }
+                            drop(guard);
+                            shared.signal.notify_all();
+                        }
+                    }
+                    Err(reason) => log_stream_error(&StreamError::Parse(reason)),
+                },
+                Ok(Message::Close(_)) => {
+                    return (StreamError::Connection("server closed connection".to_string()), true)
+                }
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(ref e))
+                    if e.kind() == std::io::ErrorKind::WouldBlock
+                        || e.kind() == std::io::ErrorKind::TimedOut =>
+                {
+                    continue;
+                }
+                Err(e) => return (StreamError::Connection(format!("read failed: {}", e)), true),
+            }
+        }
+
+        let _ = socket.close(None);
+        (StreamError::Connection("shutdown requested".to_string()), true)
+    }
+}
+
+impl Drop for OpenOrdersFeed {
+    fn drop(&mut self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// The `openOrders` channel publishes `[[{txid: {...fields}}, ...], "openOrders", ...]`.
+/// Each `{...fields}` object only carries the fields that changed since the
+/// last update (only the initial snapshot carries every field `OpenOrder`
+/// requires), so this returns the raw per-txid field maps for the caller to
+/// merge onto whatever it has accumulated for that txid so far, rather than
+/// deserializing into a full `OpenOrder` here.
+fn parse_open_orders_frame(
+    text: &str,
+) -> Result<Vec<(String, serde_json::Map<String, serde_json::Value>)>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|e| format!("{}: {}", e, text))?;
+
+    let frame = match value.as_array() {
+        Some(f) => f,
+        None => return Ok(Vec::new()),
+    };
+    if frame.get(1).and_then(|v| v.as_str()) != Some("openOrders") {
+        return Ok(Vec::new());
+    }
+
+    let updates = match frame.first().and_then(|v| v.as_array()) {
+        Some(u) => u,
+        None => return Ok(Vec::new()),
+    };
+
+    Ok(updates
+        .iter()
+        .filter_map(|entry| entry.as_object())
+        .flat_map(|entry| entry.iter())
+        .map(|(txid, fields)| (txid.clone(), fields.as_object().cloned().unwrap_or_default()))
+        .collect())
+}
+
+impl KrakenClient {
+    /// Opens a persistent authenticated WebSocket subscription to the
+    /// `openOrders` channel, re-deriving a fresh token on every reconnect.
+    /// Requires `self` in an `Arc` since the background worker re-signs
+    /// `GetWebSocketsToken` requests against it across reconnects.
+    pub fn subscribe_open_orders(self: Arc<Self>) -> OpenOrdersFeed {
+        let shared = Arc::new(OrderShared {
+            orders: Mutex::new(HashMap::new()),
+            failure: Mutex::new(None),
+            signal: Condvar::new(),
+            shutdown: AtomicBool::new(false),
+        });
+
+        let worker_shared = Arc::clone(&shared);
+        let worker = thread::spawn(move || OpenOrdersFeed::run(self, worker_shared));
+
+        OpenOrdersFeed {
+            shared,
+            worker: Some(worker),
+        }
+    }
+}
+
+impl KrakenClient {
+    /// Opens a persistent v1 WebSocket connection subscribed to the `ticker`
+    /// channel for every pair in `pairs` (e.g. `["XBT/USD", "ETH/USD"]`),
+    /// publishing the newest bid/ask for each into a background-fed cache so
+    /// callers never block on an HTTPS round-trip for a live price.
+    pub fn subscribe_ticker(&self, pairs: &[&str]) -> Result<TickerFeed, KrakenError> {
+        let shared = Arc::new(MultiShared {
+            rates: Mutex::new(HashMap::new()),
+            pending: Mutex::new(HashSet::new()),
+            failure: Mutex::new(None),
+            signal: Condvar::new(),
+            shutdown: AtomicBool::new(false),
+        });
+
+        let worker_shared = Arc::clone(&shared);
+        let pairs: Vec<String> = pairs.iter().map(|p| p.to_string()).collect();
+        let worker = thread::spawn(move || TickerFeed::run(pairs, worker_shared));
+
+        Ok(TickerFeed {
+            shared,
+            worker: Some(worker),
+        })
+    }
+}
@@ -4,9 +4,17 @@ use std::collections::HashMap;
 
 mod kraken;
 use kraken::{KrakenClient, KrakenError};
-use kraken::account::OrderResponse;
+use kraken::account::{DeadMansSwitchGuard, OpenOrder, OrderDescription, OrderResponse};
+use kraken::streaming::{OpenOrdersFeed, PriceTicker, TickerFeed};
+use std::sync::Arc;
+use std::time::Duration;
+use kraken::orderbook::OrderBook;
+use kraken::assets::PairInfo;
 mod binance_api;
 use binance_api::BinanceClient;
+mod rate;
+mod types;
+use types::{BboMsg, OrderBookMsg, Side, TradeMsg};
 
 // Generic error handler for Kraken results
 fn handle_kraken_result<T>(result: Result<T, KrakenError>) -> PyResult<T> {
@@ -97,9 +105,12 @@ fn add_order(pair: String, side: String, price: f64, volume: f64) -> PyResult<Py
 }
 
 #[pyfunction]
-fn get_recent_trades(ticker: String) -> PyResult<Vec<(f64, f64, f64, String, String, String)>> {
+fn get_recent_trades(
+    pair: String,
+    since: Option<String>,
+) -> PyResult<(Vec<(f64, f64, f64, String, String, String)>, String)> {
     let client = KrakenClient::new();
-    handle_kraken_result(client.get_recent_trades(&ticker))
+    handle_kraken_result(client.get_recent_trades(&pair, since.as_deref()))
 }
 
 #[pyfunction]
@@ -109,12 +120,52 @@ fn get_orderbook(pair: String) -> PyResult<(Vec<f64>, Vec<f64>)> {
 }
 
 #[pyfunction]
-fn get_binance_depth() -> PyResult<String> {
+fn get_binance_depth(symbol: Option<String>) -> PyResult<(Vec<(f64, f64)>, Vec<(f64, f64)>)> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_depth(symbol.as_deref()))
+}
+
+#[pyfunction]
+fn get_binance_bid(symbol: Option<String>) -> PyResult<f64> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_bid(symbol.as_deref()))
+}
+
+#[pyfunction]
+fn get_binance_ask(symbol: Option<String>) -> PyResult<f64> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_ask(symbol.as_deref()))
+}
+
+#[pyfunction]
+fn get_binance_spread(symbol: Option<String>) -> PyResult<f64> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_spread(symbol.as_deref()))
+}
+
+#[pyfunction]
+fn get_binance_balance(asset: String) -> PyResult<f64> {
     let client = BinanceClient::new()
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
-    let depth = client.get_depth()
+    handle_binance_result(client.get_balance(&asset))
+}
+
+#[pyfunction]
+fn binance_add_order(symbol: String, side: String, price: f64, quantity: f64) -> PyResult<u64> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.add_order(&symbol, &side, price, quantity))
+}
+
+#[pyfunction]
+fn binance_cancel_order(symbol: String, order_id: u64) -> PyResult<bool> {
+    let client = BinanceClient::new()
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
-    Ok(serde_json::to_string_pretty(&depth).unwrap_or_default())
+    handle_binance_result(client.cancel_order(&symbol, order_id))
 }
 
 #[pyfunction]
@@ -131,6 +182,440 @@ fn edit_order(
         .map(PyOrderResponse::from)
 }
 
+#[pyclass]
+pub struct PyPriceTicker {
+    inner: PriceTicker,
+}
+
+#[pymethods]
+impl PyPriceTicker {
+    #[staticmethod]
+    fn connect(pair: String) -> PyResult<Self> {
+        handle_kraken_result(PriceTicker::connect(&pair)).map(|inner| PyPriceTicker { inner })
+    }
+
+    fn wait_for_update(&self) -> (f64, f64) {
+        let rate = self.inner.wait_for_update();
+        (rate.bid, rate.ask)
+    }
+
+    fn latest(&self) -> Option<(f64, f64)> {
+        self.inner.latest().map(|rate| (rate.bid, rate.ask))
+    }
+}
+
+/// Python-facing handle onto a watchlist-wide v1 ticker feed covering
+/// several pairs over a single WebSocket connection.
+#[pyclass]
+pub struct PyTickerFeed {
+    inner: TickerFeed,
+}
+
+#[pymethods]
+impl PyTickerFeed {
+    #[staticmethod]
+    fn subscribe(pairs: Vec<String>) -> PyResult<Self> {
+        let client = KrakenClient::new();
+        let pair_refs: Vec<&str> = pairs.iter().map(String::as_str).collect();
+        handle_kraken_result(client.subscribe_ticker(&pair_refs)).map(|inner| PyTickerFeed { inner })
+    }
+
+    fn wait_for_update(&self) -> PyResult<(String, f64, f64)> {
+        self.inner
+            .wait_for_update()
+            .map(|(pair, rate)| (pair, rate.bid, rate.ask))
+            .map_err(pyo3::exceptions::PyRuntimeError::new_err)
+    }
+
+    fn latest(&self, pair: String) -> Option<(f64, f64)> {
+        self.inner.latest(&pair).map(|rate| (rate.bid, rate.ask))
+    }
+
+    /// True once retries are exhausted and the feed will never recover.
+    fn is_failed(&self) -> bool {
+        self.inner.is_failed()
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyOrderDescription {
+    #[pyo3(get)]
+    pub pair: String,
+    #[pyo3(get)]
+    pub order_type: String,
+    #[pyo3(get)]
+    pub ordertype: String,
+    #[pyo3(get)]
+    pub price: String,
+    #[pyo3(get)]
+    pub price2: String,
+    #[pyo3(get)]
+    pub leverage: String,
+    #[pyo3(get)]
+    pub order: String,
+    #[pyo3(get)]
+    pub close: Option<String>,
+}
+
+impl From<OrderDescription> for PyOrderDescription {
+    fn from(descr: OrderDescription) -> Self {
+        PyOrderDescription {
+            pair: descr.pair,
+            order_type: descr.order_type,
+            ordertype: descr.ordertype,
+            price: descr.price,
+            price2: descr.price2,
+            leverage: descr.leverage,
+            order: descr.order,
+            close: descr.close,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyOpenOrder {
+    #[pyo3(get)]
+    pub status: String,
+    #[pyo3(get)]
+    pub descr: PyOrderDescription,
+    #[pyo3(get)]
+    pub vol: f64,
+    #[pyo3(get)]
+    pub vol_exec: f64,
+    #[pyo3(get)]
+    pub cost: f64,
+    #[pyo3(get)]
+    pub fee: f64,
+    #[pyo3(get)]
+    pub price: f64,
+}
+
+impl From<OpenOrder> for PyOpenOrder {
+    fn from(order: OpenOrder) -> Self {
+        PyOpenOrder {
+            status: order.status,
+            descr: PyOrderDescription::from(order.descr),
+            vol: order.vol,
+            vol_exec: order.vol_exec,
+            cost: order.cost,
+            fee: order.fee,
+            price: order.price,
+        }
+    }
+}
+
+/// Python-facing handle onto a live `openOrders` WebSocket subscription, so
+/// order-state changes and fills arrive as a push feed instead of a poll.
+#[pyclass]
+pub struct PyOpenOrdersFeed {
+    inner: OpenOrdersFeed,
+}
+
+#[pymethods]
+impl PyOpenOrdersFeed {
+    #[staticmethod]
+    fn subscribe() -> Self {
+        let client = Arc::new(KrakenClient::new());
+        PyOpenOrdersFeed {
+            inner: client.subscribe_open_orders(),
+        }
+    }
+
+    fn wait_for_update(&self) -> PyResult<(String, PyOpenOrder)> {
+        self.inner
+            .wait_for_update()
+            .map(|(txid, order)| (txid, PyOpenOrder::from(order)))
+            .map_err(pyo3::exceptions::PyRuntimeError::new_err)
+    }
+
+    /// True once retries are exhausted and the feed will never recover.
+    fn is_failed(&self) -> bool {
+        self.inner.is_failed()
+    }
+}
+
+/// Python-facing handle onto a background thread that keeps the exchange's
+/// dead man's switch re-armed, so a crashed process still gets its orders
+/// cancelled after `timeout_secs`. Dropping (or explicitly disarming) it
+/// stops the keep-alive.
+#[pyclass]
+pub struct PyDeadMansSwitchGuard {
+    inner: Option<DeadMansSwitchGuard>,
+}
+
+#[pymethods]
+impl PyDeadMansSwitchGuard {
+    #[staticmethod]
+    fn arm(timeout_secs: u64, rearm_interval_secs: u64) -> Self {
+        let client = Arc::new(KrakenClient::new());
+        PyDeadMansSwitchGuard {
+            inner: Some(client.arm_dead_mans_switch_keepalive(
+                timeout_secs,
+                Duration::from_secs(rearm_interval_secs),
+            )),
+        }
+    }
+
+    /// Stops the keep-alive and disarms the timer immediately, instead of
+    /// waiting for garbage collection to drop the guard.
+    fn disarm(&mut self) {
+        self.inner.take();
+    }
+}
+
+/// Python-facing handle onto a live, checksum-reconciled local order book.
+/// Callers feed it raw `book` channel frames - the full JSON array Kraken
+/// sends (`[channelID, {...}, "book-10", "XBT/USD"]`) - as they arrive off a
+/// WebSocket subscription.
+#[pyclass]
+pub struct PyOrderBook {
+    inner: OrderBook,
+}
+
+#[pymethods]
+impl PyOrderBook {
+    #[new]
+    fn new(depth: usize) -> Self {
+        PyOrderBook {
+            inner: OrderBook::new(depth),
+        }
+    }
+
+    /// Ingests one `book` channel message. Returns `false` if a checksum
+    /// mismatch forced the local book to be dropped; the caller should
+    /// resubscribe for a fresh snapshot in that case.
+    fn ingest(&mut self, message: &str) -> PyResult<bool> {
+        let payload: serde_json::Value = serde_json::from_str(message)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        handle_kraken_result(self.inner.ingest(&payload))
+    }
+
+    fn top_of_book(&self) -> Option<((f64, f64), (f64, f64))> {
+        self.inner.top_of_book()
+    }
+
+    fn bids(&self) -> Vec<(f64, f64)> {
+        self.inner.bids()
+    }
+
+    fn asks(&self) -> Vec<(f64, f64)> {
+        self.inner.asks()
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyTradeMsg {
+    #[pyo3(get)]
+    pub exchange: String,
+    #[pyo3(get)]
+    pub symbol: String,
+    #[pyo3(get)]
+    pub timestamp: f64,
+    #[pyo3(get)]
+    pub side: String,
+    #[pyo3(get)]
+    pub price: f64,
+    #[pyo3(get)]
+    pub quantity: f64,
+}
+
+#[pymethods]
+impl PyTradeMsg {
+    fn quote_volume(&self) -> f64 {
+        self.price * self.quantity
+    }
+}
+
+impl From<TradeMsg> for PyTradeMsg {
+    fn from(msg: TradeMsg) -> Self {
+        PyTradeMsg {
+            exchange: msg.exchange,
+            symbol: msg.symbol,
+            timestamp: msg.timestamp,
+            side: match msg.side {
+                Side::Buy => "buy".to_string(),
+                Side::Sell => "sell".to_string(),
+            },
+            price: msg.price,
+            quantity: msg.quantity,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyBboMsg {
+    #[pyo3(get)]
+    pub exchange: String,
+    #[pyo3(get)]
+    pub symbol: String,
+    #[pyo3(get)]
+    pub timestamp: f64,
+    #[pyo3(get)]
+    pub bid_price: f64,
+    #[pyo3(get)]
+    pub bid_quantity: f64,
+    #[pyo3(get)]
+    pub ask_price: f64,
+    #[pyo3(get)]
+    pub ask_quantity: f64,
+}
+
+#[pymethods]
+impl PyBboMsg {
+    fn mid(&self) -> f64 {
+        (self.bid_price + self.ask_price) / 2.0
+    }
+
+    fn spread(&self) -> f64 {
+        self.ask_price - self.bid_price
+    }
+}
+
+impl From<BboMsg> for PyBboMsg {
+    fn from(msg: BboMsg) -> Self {
+        PyBboMsg {
+            exchange: msg.exchange,
+            symbol: msg.symbol,
+            timestamp: msg.timestamp,
+            bid_price: msg.bid_price,
+            bid_quantity: msg.bid_quantity,
+            ask_price: msg.ask_price,
+            ask_quantity: msg.ask_quantity,
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyOrderBookMsg {
+    #[pyo3(get)]
+    pub exchange: String,
+    #[pyo3(get)]
+    pub symbol: String,
+    #[pyo3(get)]
+    pub timestamp: f64,
+    #[pyo3(get)]
+    pub bids: Vec<(f64, f64)>,
+    #[pyo3(get)]
+    pub asks: Vec<(f64, f64)>,
+}
+
+impl From<OrderBookMsg> for PyOrderBookMsg {
+    fn from(msg: OrderBookMsg) -> Self {
+        PyOrderBookMsg {
+            exchange: msg.exchange,
+            symbol: msg.symbol,
+            timestamp: msg.timestamp,
+            bids: msg.bids,
+            asks: msg.asks,
+        }
+    }
+}
+
+#[pyfunction]
+fn get_bbo(pair: String) -> PyResult<PyBboMsg> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.get_bbo(&pair)).map(PyBboMsg::from)
+}
+
+#[pyfunction]
+fn get_orderbook_msg(pair: String) -> PyResult<PyOrderBookMsg> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.get_orderbook_msg(&pair)).map(PyOrderBookMsg::from)
+}
+
+#[pyfunction]
+fn get_trade_msgs(pair: String, since: Option<String>) -> PyResult<(Vec<PyTradeMsg>, String)> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.get_trade_msgs(&pair, since.as_deref()))
+        .map(|(msgs, last)| (msgs.into_iter().map(PyTradeMsg::from).collect(), last))
+}
+
+#[pyfunction]
+fn get_binance_bbo(symbol: Option<String>) -> PyResult<PyBboMsg> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_bbo_msg(symbol.as_deref())).map(PyBboMsg::from)
+}
+
+#[pyfunction]
+fn get_binance_orderbook_msg(symbol: Option<String>) -> PyResult<PyOrderBookMsg> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_orderbook_msg(symbol.as_deref())).map(PyOrderBookMsg::from)
+}
+
+#[pyfunction]
+fn get_binance_trade_msgs(symbol: Option<String>) -> PyResult<Vec<PyTradeMsg>> {
+    let client = BinanceClient::new()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("{:?}", e)))?;
+    handle_binance_result(client.get_trade_msgs(symbol.as_deref()))
+        .map(|msgs| msgs.into_iter().map(PyTradeMsg::from).collect())
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PyPairInfo {
+    #[pyo3(get)]
+    pub altname: String,
+    #[pyo3(get)]
+    pub wsname: String,
+    #[pyo3(get)]
+    pub base: String,
+    #[pyo3(get)]
+    pub quote: String,
+    #[pyo3(get)]
+    pub price_decimals: u32,
+    #[pyo3(get)]
+    pub volume_decimals: u32,
+    #[pyo3(get)]
+    pub ordermin: f64,
+}
+
+impl From<PairInfo> for PyPairInfo {
+    fn from(info: PairInfo) -> Self {
+        PyPairInfo {
+            altname: info.altname,
+            wsname: info.wsname,
+            base: info.base,
+            quote: info.quote,
+            price_decimals: info.price_decimals,
+            volume_decimals: info.volume_decimals,
+            ordermin: info.ordermin,
+        }
+    }
+}
+
+#[pyfunction]
+fn get_asset_pairs() -> PyResult<HashMap<String, PyPairInfo>> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.get_asset_pairs())
+        .map(|pairs| pairs.into_iter().map(|(k, v)| (k, PyPairInfo::from(v))).collect())
+}
+
+#[pyfunction]
+fn find_pair(query: String) -> PyResult<Option<PyPairInfo>> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.find_pair(&query)).map(|found| found.map(PyPairInfo::from))
+}
+
+#[pyfunction]
+fn cancel_all_orders_after(timeout_secs: u64) -> PyResult<(String, String)> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.cancel_all_orders_after(timeout_secs))
+        .map(|armed| (armed.current_time, armed.trigger_time))
+}
+
+#[pyfunction]
+fn get_websockets_token() -> PyResult<String> {
+    let client = KrakenClient::new();
+    handle_kraken_result(client.get_websockets_token())
+}
+
 #[pymodule]
 fn rust_kraken_client(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_open_orders_raw, m)?)?;
@@ -143,7 +628,34 @@ fn rust_kraken_client(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()>
     m.add_function(wrap_pyfunction!(get_recent_trades, m)?)?;
     m.add_function(wrap_pyfunction!(get_orderbook, m)?)?;
     m.add_function(wrap_pyfunction!(get_binance_depth, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_bid, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_ask, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_spread, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_balance, m)?)?;
+    m.add_function(wrap_pyfunction!(binance_add_order, m)?)?;
+    m.add_function(wrap_pyfunction!(binance_cancel_order, m)?)?;
     m.add_function(wrap_pyfunction!(edit_order, m)?)?;
+    m.add_function(wrap_pyfunction!(get_bbo, m)?)?;
+    m.add_function(wrap_pyfunction!(get_orderbook_msg, m)?)?;
+    m.add_function(wrap_pyfunction!(get_trade_msgs, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_bbo, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_orderbook_msg, m)?)?;
+    m.add_function(wrap_pyfunction!(get_binance_trade_msgs, m)?)?;
+    m.add_function(wrap_pyfunction!(get_asset_pairs, m)?)?;
+    m.add_function(wrap_pyfunction!(find_pair, m)?)?;
+    m.add_function(wrap_pyfunction!(cancel_all_orders_after, m)?)?;
+    m.add_function(wrap_pyfunction!(get_websockets_token, m)?)?;
     m.add_class::<PyOrderResponse>()?;
+    m.add_class::<PyPriceTicker>()?;
+    m.add_class::<PyTickerFeed>()?;
+    m.add_class::<PyOrderDescription>()?;
+    m.add_class::<PyOpenOrder>()?;
+    m.add_class::<PyOpenOrdersFeed>()?;
+    m.add_class::<PyDeadMansSwitchGuard>()?;
+    m.add_class::<PyOrderBook>()?;
+    m.add_class::<PyTradeMsg>()?;
+    m.add_class::<PyBboMsg>()?;
+    m.add_class::<PyOrderBookMsg>()?;
+    m.add_class::<PyPairInfo>()?;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,80 @@
+use crate::binance_api::{BinanceClient, BinanceError};
+use crate::kraken::{KrakenClient, KrakenError};
+
+/// A bid/ask snapshot from any venue.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rate {
+    pub bid: f64,
+    pub ask: f64,
+}
+
+impl Rate {
+    pub fn mid(&self) -> f64 {
+        (self.bid + self.ask) / 2.0
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.ask - self.bid
+    }
+}
+
+/// Implemented by anything that can report a current bid/ask, so strategy
+/// code can be written against a venue-agnostic price source.
+pub trait LatestRate {
+    type Error;
+
+    fn latest_rate(&mut self) -> Result<Rate, Self::Error>;
+}
+
+impl LatestRate for KrakenClient {
+    type Error = KrakenError;
+
+    /// One `Ticker` round-trip via `get_bbo` instead of separate `get_bid`
+    /// and `get_ask` calls, so strategy code polling through this trait
+    /// doesn't double the HTTP traffic it needs.
+    fn latest_rate(&mut self) -> Result<Rate, KrakenError> {
+        let pair = self.pair.clone();
+        let bbo = self.get_bbo(&pair)?;
+        Ok(Rate {
+            bid: bbo.bid_price,
+            ask: bbo.ask_price,
+        })
+    }
+}
+
+impl LatestRate for BinanceClient {
+    type Error = BinanceError;
+
+    /// One `bookTicker` round-trip via `get_bbo_msg` instead of separate
+    /// `get_bid` and `get_ask` calls.
+    fn latest_rate(&mut self) -> Result<Rate, BinanceError> {
+        let bbo = self.get_bbo_msg(None)?;
+        Ok(Rate {
+            bid: bbo.bid_price,
+            ask: bbo.ask_price,
+        })
+    }
+}
+
+/// A `LatestRate` source that always returns a fixed, configured rate.
+/// Useful for backtests and unit tests that need a deterministic price
+/// without hitting the network.
+pub struct FixedRate {
+    rate: Rate,
+}
+
+impl FixedRate {
+    pub fn new(bid: f64, ask: f64) -> Self {
+        FixedRate {
+            rate: Rate { bid, ask },
+        }
+    }
+}
+
+impl LatestRate for FixedRate {
+    type Error = std::convert::Infallible;
+
+    fn latest_rate(&mut self) -> Result<Rate, Self::Error> {
+        Ok(self.rate)
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Normalized market-data layer shared by every venue, so strategy code can
+/// consume Kraken and Binance feeds through one set of types instead of
+/// special-casing each exchange's raw response shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone)]
+pub struct TradeMsg {
+    pub exchange: String,
+    pub symbol: String,
+    pub timestamp: f64,
+    pub side: Side,
+    pub price: f64,
+    pub quantity: f64,
+}
+
+impl TradeMsg {
+    pub fn quote_volume(&self) -> f64 {
+        self.price * self.quantity
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BboMsg {
+    pub exchange: String,
+    pub symbol: String,
+    pub timestamp: f64,
+    pub bid_price: f64,
+    pub bid_quantity: f64,
+    pub ask_price: f64,
+    pub ask_quantity: f64,
+}
+
+impl BboMsg {
+    pub fn mid(&self) -> f64 {
+        (self.bid_price + self.ask_price) / 2.0
+    }
+
+    pub fn spread(&self) -> f64 {
+        self.ask_price - self.bid_price
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderBookMsg {
+    pub exchange: String,
+    pub symbol: String,
+    pub timestamp: f64,
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+pub fn now_timestamp() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs_f64()
+}
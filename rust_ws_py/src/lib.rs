@@ -2,10 +2,44 @@ use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 use serde::{Deserialize};
 use std::fs;
-use tungstenite::{connect, Message};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{connect, Message, WebSocket};
 use url::Url;
 use std::collections::HashMap;
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
 use reqwest::blocking::Client;
+use hmac::{Hmac, Mac};
+use sha2::{Sha256, Sha512, Digest};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn generate_nonce() -> String {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_millis()
+        .to_string()
+}
+
+/// Computes Kraken's `API-Sign` header: HMAC-SHA512 (keyed with the
+/// base64-decoded secret) over the URI path followed by
+/// `sha256(nonce + body)`, base64-encoded.
+fn sign_request(path: &str, nonce: &str, body: &str, api_secret: &str) -> String {
+    let nonce_plus_data = format!("{}{}", nonce, body);
+    let sha256_digest = Sha256::digest(nonce_plus_data.as_bytes());
+
+    let mut message = path.as_bytes().to_vec();
+    message.extend_from_slice(&sha256_digest);
+
+    let decoded_secret = STANDARD.decode(api_secret).expect("Invalid API secret");
+    let mut mac = Hmac::<Sha512>::new_from_slice(&decoded_secret).expect("Invalid key length");
+    mac.update(&message);
+    STANDARD.encode(mac.finalize().into_bytes())
+}
 
 #[pyfunction]
 fn test_connection() -> PyResult<()> {
@@ -49,24 +83,35 @@ fn load_config() -> KrakenCredentials {
     cfg.kraken
 }
 
-fn get_token(api_key: &str, api_secret: &str) -> String {
+fn get_token(api_key: &str, api_secret: &str) -> Result<String, String> {
     let client = Client::new();
+    let nonce = generate_nonce();
+    let body = format!("nonce={}", nonce);
+    let path = "/0/private/GetWebSocketsToken";
+    let signature = sign_request(path, &nonce, &body, api_secret);
+
     let res = client
-        .post("https://api.kraken.com/0/private/GetWebSocketsToken")
+        .post(format!("https://api.kraken.com{}", path))
         .header("API-Key", api_key)
-        .header("API-Sign", api_secret)  // Kraken expects API-Sign for private endpoints (optional here)
+        .header("API-Sign", signature)
         .header("Content-Type", "application/x-www-form-urlencoded")
-        .body("")
+        .body(body)
         .send()
-        .expect("Failed to get token");
+        .map_err(|e| format!("token request failed: {}", e))?;
 
-    let json: HashMap<String, serde_json::Value> = res.json().expect("Invalid JSON");
-    json["result"]["token"].as_str().unwrap().to_string()
+    let json: HashMap<String, serde_json::Value> =
+        res.json().map_err(|e| format!("invalid token response: {}", e))?;
+    json.get("result")
+        .and_then(|r| r.get("token"))
+        .and_then(|t| t.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| "token missing from response".to_string())
 }
 
-fn connect_auth_socket(token: &str) -> tungstenite::WebSocket<tungstenite::stream::MaybeTlsStream<std::net::TcpStream>> {
-    let url = Url::parse("wss://ws-auth.kraken.com").unwrap();
-    let (mut socket, _) = connect(url).expect("Failed to connect to auth WebSocket");
+fn connect_auth_socket(token: &str) -> Result<tungstenite::WebSocket<tungstenite::stream::MaybeTlsStream<std::net::TcpStream>>, String> {
+    let url = Url::parse("wss://ws-auth.kraken.com")
+        .map_err(|e| format!("URL parse error: {}", e))?;
+    let (mut socket, _) = connect(url).map_err(|e| format!("failed to connect to auth WebSocket: {}", e))?;
 
     let login_msg = serde_json::json!({
         "event": "subscribe",
@@ -75,15 +120,248 @@ fn connect_auth_socket(token: &str) -> tungstenite::WebSocket<tungstenite::strea
             "token": token
         }
     });
-    socket.write_message(Message::Text(login_msg.to_string())).unwrap();
     socket
+        .write_message(Message::Text(login_msg.to_string()))
+        .map_err(|e| format!("failed to send subscribe message: {}", e))?;
+    Ok(socket)
+}
+
+const READ_TIMEOUT: Duration = Duration::from_millis(500);
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+const MAX_RECONNECT_ATTEMPTS: u32 = 10;
+
+/// A single connection attempt either dies because the socket/handshake
+/// failed (worth a backed-off reconnect) or because one frame didn't parse
+/// (worth logging and skipping, not tearing down the connection).
+enum StreamError {
+    Connection(String),
+    Parse(String),
+}
+
+struct FeedState {
+    latest: Mutex<Option<String>>,
+    /// Set when `latest` is replaced, cleared when `wait_for_update` returns
+    /// it; only changed while holding the `latest` lock.
+    fresh: AtomicBool,
+    failure: Mutex<Option<String>>,
+    signal: Condvar,
+    shutdown: AtomicBool,
+}
+
+impl FeedState {
+    fn publish(&self, text: String) {
+        let mut guard = self.latest.lock().expect("feed mutex poisoned");
+        *guard = Some(text);
+        self.fresh.store(true, Ordering::Relaxed);
+        drop(guard);
+        self.signal.notify_all();
+    }
+}
+
+/// Python-facing handle onto a self-healing WebSocket feed. Subscribers only
+/// ever see the freshest frame or a terminal failure - never a raw
+/// connection hiccup.
+#[pyclass]
+pub struct PyFeed {
+    state: Arc<FeedState>,
+    worker: Option<JoinHandle<()>>,
+}
+
+#[pymethods]
+impl PyFeed {
+    /// Blocks until a new frame arrives and returns its raw JSON text, or
+    /// raises once the feed has permanently failed.
+    fn wait_for_update(&self) -> PyResult<String> {
+        let mut guard = self.state.latest.lock().expect("feed mutex poisoned");
+        loop {
+            if self.state.fresh.swap(false, Ordering::Relaxed) {
+                if let Some(text) = guard.clone() {
+                    return Ok(text);
+                }
+            }
+            if let Some(reason) = self.state.failure.lock().expect("feed mutex poisoned").clone() {
+                return Err(pyo3::exceptions::PyRuntimeError::new_err(reason));
+            }
+            let (next_guard, _) = self
+                .state
+                .signal
+                .wait_timeout(guard, Duration::from_millis(500))
+                .expect("feed mutex poisoned");
+            guard = next_guard;
+        }
+    }
+
+    /// Returns the latest frame without waiting, if one has arrived yet.
+    fn latest(&self) -> Option<String> {
+        self.state.latest.lock().expect("feed mutex poisoned").clone()
+    }
+
+    /// True once retries are exhausted and the feed will never recover.
+    fn is_failed(&self) -> bool {
+        self.state.failure.lock().expect("feed mutex poisoned").is_some()
+    }
+}
+
+impl Drop for PyFeed {
+    fn drop(&mut self) {
+        self.state.shutdown.store(true, Ordering::Relaxed);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn set_read_timeout(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, timeout: Option<Duration>) {
+    match socket.get_ref() {
+        MaybeTlsStream::Plain(stream) => {
+            let _ = stream.set_read_timeout(timeout);
+        }
+        #[cfg(feature = "native-tls")]
+        MaybeTlsStream::NativeTls(stream) => {
+            let _ = stream.get_ref().set_read_timeout(timeout);
+        }
+        _ => {}
+    }
+}
+
+fn log_stream_error(err: StreamError) {
+    match err {
+        StreamError::Connection(reason) => eprintln!("stream connection error: {}", reason),
+        StreamError::Parse(reason) => eprintln!("dropping malformed frame: {}", reason),
+    }
+}
+
+/// Drives a reconnect loop: `attempt` makes one connection attempt and
+/// returns its `StreamError` plus whether the connection was ever actually
+/// established (so a blip after a long healthy run doesn't count against
+/// the retry budget). Retries `StreamError::Connection` with exponential
+/// backoff, resetting on success; after `MAX_RECONNECT_ATTEMPTS` consecutive
+/// failures to even connect, gives up and records the reason into
+/// `state.failure` so `wait_for_update` can surface a terminal error instead
+/// of blocking forever.
+fn run_reconnect_loop(state: &Arc<FeedState>, mut attempt: impl FnMut() -> (StreamError, bool)) {
+    let mut backoff = INITIAL_BACKOFF;
+    let mut consecutive_connect_failures = 0u32;
+
+    while !state.shutdown.load(Ordering::Relaxed) {
+        let (err, connected) = attempt();
+        if state.shutdown.load(Ordering::Relaxed) {
+            return;
+        }
+
+        match err {
+            StreamError::Connection(reason) => {
+                if connected {
+                    consecutive_connect_failures = 0;
+                    backoff = INITIAL_BACKOFF;
+                } else {
+                    consecutive_connect_failures += 1;
+                }
+
+                if consecutive_connect_failures >= MAX_RECONNECT_ATTEMPTS {
+                    let message = format!(
+                        "giving up after {} consecutive failed connection attempts: {}",
+                        consecutive_connect_failures, reason
+                    );
+                    eprintln!("stream permanently failed: {}", message);
+                    *state.failure.lock().expect("feed mutex poisoned") = Some(message);
+                    state.signal.notify_all();
+                    return;
+                }
+
+                eprintln!("stream reconnecting after: {}", reason);
+                thread::sleep(backoff);
+                backoff = std::cmp::min(backoff * 2, MAX_BACKOFF);
+            }
+            StreamError::Parse(reason) => log_stream_error(StreamError::Parse(reason)),
+        }
+    }
+}
+
+/// Streams frames from a single connection attempt, publishing every valid
+/// text frame and skipping malformed ones in place. Returns once the socket
+/// itself needs to be re-dialed (or the feed was asked to shut down), plus
+/// whether the socket ever got far enough to subscribe - i.e. whether this
+/// was a healthy connection that later dropped, versus a failure to connect
+/// at all.
+fn stream_connection(
+    url: &str,
+    subscribe_msg: &serde_json::Value,
+    state: &Arc<FeedState>,
+) -> (StreamError, bool) {
+    let parsed = match Url::parse(url) {
+        Ok(u) => u,
+        Err(e) => return (StreamError::Connection(format!("bad URL: {}", e)), false),
+    };
+    let (mut socket, _) = match connect(parsed) {
+        Ok(pair) => pair,
+        Err(e) => return (StreamError::Connection(format!("connect failed: {}", e)), false),
+    };
+    set_read_timeout(&mut socket, Some(READ_TIMEOUT));
+
+    if let Err(e) = socket.write_message(Message::Text(subscribe_msg.to_string())) {
+        return (StreamError::Connection(format!("subscribe failed: {}", e)), false);
+    }
+
+    loop {
+        if state.shutdown.load(Ordering::Relaxed) {
+            let _ = socket.close(None);
+            return (StreamError::Connection("shutdown requested".to_string()), true);
+        }
+
+        match socket.read_message() {
+            Ok(Message::Text(text)) => {
+                if let Err(e) = serde_json::from_str::<serde_json::Value>(&text) {
+                    log_stream_error(StreamError::Parse(format!("{}: {}", e, text)));
+                    continue;
+                }
+                state.publish(text);
+            }
+            Ok(Message::Close(_)) => {
+                return (StreamError::Connection("server closed connection".to_string()), true)
+            }
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(ref e))
+                if e.kind() == std::io::ErrorKind::WouldBlock
+                    || e.kind() == std::io::ErrorKind::TimedOut =>
+            {
+                continue;
+            }
+            Err(e) => return (StreamError::Connection(format!("read failed: {}", e)), true),
+        }
+    }
+}
+
+/// Spawns the reconnect loop. See [`run_reconnect_loop`] for the
+/// backoff/terminal-failure semantics.
+fn spawn_feed(url: String, subscribe_msg: serde_json::Value) -> PyFeed {
+    let state = Arc::new(FeedState {
+        latest: Mutex::new(None),
+        fresh: AtomicBool::new(false),
+        failure: Mutex::new(None),
+        signal: Condvar::new(),
+        shutdown: AtomicBool::new(false),
+    });
+
+    let worker_state = Arc::clone(&state);
+    let worker = thread::spawn(move || {
+        run_reconnect_loop(&worker_state, || stream_connection(&url, &subscribe_msg, &worker_state));
+    });
+
+    PyFeed {
+        state,
+        worker: Some(worker),
+    }
 }
 
 #[pyfunction]
-fn add_order(pair: String, side: String, volume: f64, ordertype: String) {
+fn add_order(pair: String, side: String, volume: f64, ordertype: String) -> PyResult<String> {
     let cfg = load_config();
-    let token = get_token(&cfg.api_key, &cfg.api_secret);
-    let mut socket = connect_auth_socket(&token);
+    let token = get_token(&cfg.api_key, &cfg.api_secret)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e))?;
+    let mut socket = connect_auth_socket(&token)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e))?;
 
     let msg = serde_json::json!({
         "event": "addOrder",
@@ -94,63 +372,90 @@ fn add_order(pair: String, side: String, volume: f64, ordertype: String) {
         "pair": pair
     });
 
-    socket.write_message(Message::Text(msg.to_string())).unwrap();
-    if let Ok(msg) = socket.read_message() {
-        if let Message::Text(txt) = msg {
-            println!("{}", txt);
-        }
+    socket
+        .write_message(Message::Text(msg.to_string()))
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("failed to send addOrder message: {}", e)))?;
+
+    let response = socket
+        .read_message()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("failed to read addOrder response: {}", e)))?;
+    match response {
+        Message::Text(txt) => Ok(txt),
+        _ => Ok(String::new()),
     }
 }
 
 #[pyfunction]
-fn get_orders() {
-    let cfg = load_config();
-    let token = get_token(&cfg.api_key, &cfg.api_secret);
-    let mut socket = connect_auth_socket(&token);
-
+fn get_orders() -> PyFeed {
+    // Each reconnect re-derives a fresh token, since a WebSockets token is
+    // only valid for 15 minutes.
     let msg = serde_json::json!({
         "event": "subscribe",
-        "subscription": {
-            "name": "openOrders",
-            "token": token
-        }
+        "subscription": { "name": "openOrders" }
     });
+    spawn_private_feed("wss://ws-auth.kraken.com".to_string(), msg)
+}
 
-    socket.write_message(Message::Text(msg.to_string())).unwrap();
-    if let Ok(msg) = socket.read_message() {
-        if let Message::Text(txt) = msg {
-            println!("{}", txt);
-        }
+fn spawn_private_feed(url: String, mut subscribe_msg: serde_json::Value) -> PyFeed {
+    let state = Arc::new(FeedState {
+        latest: Mutex::new(None),
+        fresh: AtomicBool::new(false),
+        failure: Mutex::new(None),
+        signal: Condvar::new(),
+        shutdown: AtomicBool::new(false),
+    });
+
+    let worker_state = Arc::clone(&state);
+    let worker = thread::spawn(move || {
+        run_reconnect_loop(&worker_state, || {
+            let cfg = load_config();
+            let token = match get_token(&cfg.api_key, &cfg.api_secret) {
+                Ok(t) => t,
+                Err(e) => return (StreamError::Connection(format!("token fetch failed: {}", e)), false),
+            };
+            subscribe_msg["subscription"]["token"] = serde_json::Value::String(token);
+
+            stream_connection(&url, &subscribe_msg, &worker_state)
+        });
+    });
+
+    PyFeed {
+        state,
+        worker: Some(worker),
     }
 }
 
 #[pyfunction]
-fn close_orders(txid: Vec<String>) {
+fn close_orders(txid: Vec<String>) -> PyResult<Vec<String>> {
     let cfg = load_config();
-    let token = get_token(&cfg.api_key, &cfg.api_secret);
-    let mut socket = connect_auth_socket(&token);
+    let token = get_token(&cfg.api_key, &cfg.api_secret)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e))?;
+    let mut socket = connect_auth_socket(&token)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e))?;
 
+    let mut responses = Vec::with_capacity(txid.len());
     for id in txid {
         let msg = serde_json::json!({
             "event": "cancelOrder",
             "token": token,
             "txid": id
         });
-        socket.write_message(Message::Text(msg.to_string())).unwrap();
+        socket
+            .write_message(Message::Text(msg.to_string()))
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("failed to send cancelOrder message: {}", e)))?;
 
-        if let Ok(msg) = socket.read_message() {
-            if let Message::Text(txt) = msg {
-                println!("{}", txt);
-            }
+        let response = socket
+            .read_message()
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(format!("failed to read cancelOrder response: {}", e)))?;
+        if let Message::Text(txt) = response {
+            responses.push(txt);
         }
     }
+    Ok(responses)
 }
 
 #[pyfunction]
-fn get_orderbook(pair: String, depth: u32) {
-    let url = Url::parse("wss://ws.kraken.com").unwrap();
-    let (mut socket, _) = connect(url).expect("WebSocket connection failed");
-
+fn get_orderbook(pair: String, depth: u32) -> PyFeed {
     let msg = serde_json::json!({
         "event": "subscribe",
         "subscription": {
@@ -159,21 +464,11 @@ fn get_orderbook(pair: String, depth: u32) {
         },
         "pair": [pair]
     });
-
-    socket.write_message(Message::Text(msg.to_string())).unwrap();
-
-    if let Ok(msg) = socket.read_message() {
-        if let Message::Text(txt) = msg {
-            println!("{}", txt);
-        }
-    }
+    spawn_feed("wss://ws.kraken.com".to_string(), msg)
 }
 
 #[pyfunction]
-fn subscribe(pair: String) {
-    let url = Url::parse("wss://ws.kraken.com").unwrap();
-    let (mut socket, _) = connect(url).expect("WebSocket connection failed");
-
+fn subscribe(pair: String) -> PyFeed {
     let msg = serde_json::json!({
         "event": "subscribe",
         "subscription": {
@@ -181,14 +476,7 @@ fn subscribe(pair: String) {
         },
         "pair": [pair]
     });
-
-    socket.write_message(Message::Text(msg.to_string())).unwrap();
-
-    if let Ok(msg) = socket.read_message() {
-        if let Message::Text(txt) = msg {
-            println!("{}", txt);
-        }
-    }
+    spawn_feed("wss://ws.kraken.com".to_string(), msg)
 }
 
 #[pymodule]
@@ -199,5 +487,6 @@ fn rust_ws_py(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_orders, m)?)?;
     m.add_function(wrap_pyfunction!(close_orders, m)?)?;
     m.add_function(wrap_pyfunction!(test_connection, m)?)?;
+    m.add_class::<PyFeed>()?;
     Ok(())
 }